@@ -1,4 +1,6 @@
 //! A simple crate that adds a variety of Godot (specifically gdext) related macros for convenience.
+//! 
+//! Targets gdext 0.4, from 0.4.4 onwards; the expansions shown in each macro's docs use its API.
 
 // TODO: Readme!
 
@@ -10,386 +12,4332 @@
 /// 
 /// # Example
 /// 
-/// ```
+/// ```ignore
 /// let label: Gd<Node> = n!(self, "Player"); // Reference to a child Node named "Player"
 /// let label: Gd<Label> = n!(self, Label, "Player"); // Reference to a child Label named "Player"
 /// let label: Gd<Label> = n!(self, "Player", Label); // Same but with type and name switched
 /// 
 /// // Expanded
-/// let label: Gd<Node> = self.base().get_node_as::<Node>("Player");
-/// let label: Gd<Label> = self.base().get_node_as::<Label>("Player");
+/// let label: Gd<Node> = self.base().get_node_as::<Node>("Player");
+/// let label: Gd<Label> = self.base().get_node_as::<Label>("Player");
+/// ```
+#[macro_export]
+macro_rules! n {
+    ($self:ident, $node_path:expr) => {
+        $self.base().get_node_as::<Node>($node_path)
+    };
+    ($self:ident, $node_type:ty, $node_path:expr) => {
+        $self.base().get_node_as::<$node_type>($node_path)
+    };
+    ($self:ident, $node_path:expr, $node_type:ty) => {
+        $self.base().get_node_as::<$node_type>($node_path)
+    };
+}
+
+/// Macro for quickly getting a mutable reference to a node from its path. Can choose to specify node type, otherwise defaults to Node.
+///
+/// # Panics
+/// 
+/// Panics if node_path cannot be found.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// let mut label: Gd<Node> = nm!(self, "Player"); // Mutable reference to a child Node named "Player"
+/// let mut label: Gd<Label> = nm!(self, Label, "Player"); // Mutable reference to a child Label named "Player"
+/// let mut label: Gd<Label> = nm!(self, "Player", Label); // Same but with type and name switched
+/// 
+/// // Expanded
+/// let mut label: Gd<Node> = self.base_mut().get_node_as::<Node>("Player");
+/// let mut label: Gd<Label> = self.base_mut().get_node_as::<Label>("Player");
+/// ```
+#[macro_export]
+macro_rules! nm {
+    ($self:ident, $node_path:expr) => {
+        $self.base_mut().get_node_as::<Node>($node_path)
+    };
+    ($self:ident, $node_type:ty, $node_path:expr) => {
+        $self.base_mut().get_node_as::<$node_type>($node_path)
+    };
+    ($self:ident, $node_path:expr, $node_type:ty) => {
+        $self.base_mut().get_node_as::<$node_type>($node_path)
+    };
+}
+
+/// Macro for quickly getting a non-mutable reference to a node from its path without panicking. Can choose to specify node type, otherwise defaults to Node.
+///
+/// Returns None if node_path cannot be found or the node is not of the given type.
+///
+/// # Example
+///
+/// ```ignore
+/// let player: Option<Gd<Node>> = try_n!(self, "Player"); // Reference to a child Node named "Player", if it exists
+/// let player: Option<Gd<Player>> = try_n!(self, Player, "Player"); // Reference to a child Player named "Player", if it exists
+/// let player: Option<Gd<Player>> = try_n!(self, "Player", Player); // Same but with type and name switched
+///
+/// // Handle the node not existing yet
+/// if let Some(player) = try_n!(self, Player, "Player") {
+///     // Player exists
+/// } else {
+///     // Player hasn't been added to the scene yet
+/// }
+///
+/// // Expanded
+/// let player: Option<Gd<Node>> = self.base().try_get_node_as::<Node>("Player");
+/// let player: Option<Gd<Player>> = self.base().try_get_node_as::<Player>("Player");
+/// ```
+/// 
+/// ```
+/// # use godot_macros::*;
+/// # #[derive(Debug, Default, PartialEq)] struct Node;
+/// # #[derive(Debug, Default, PartialEq)] struct Player;
+/// # struct Base;
+/// # impl Base {
+/// #     fn try_get_node_as<T: Default>(&self, node_path: &str) -> Option<T> { (node_path == "Player").then(T::default) }
+/// # }
+/// # struct Main { base: Base }
+/// # impl Main {
+/// #     fn base(&self) -> &Base { &self.base }
+/// #     fn ready(&mut self) {
+/// assert_eq!(try_n!(self, "Player"), Some(Node));
+/// assert_eq!(try_n!(self, Player, "Player"), Some(Player));
+/// assert_eq!(try_n!(self, "Player", Player), Some(Player));
+/// 
+/// // Every arm returns None instead of panicking when the node doesn't exist
+/// assert_eq!(try_n!(self, "Enemy"), None);
+/// assert_eq!(try_n!(self, Player, "Enemy"), None);
+/// assert_eq!(try_n!(self, "Enemy", Player), None);
+/// #     }
+/// # }
+/// # Main { base: Base }.ready();
+/// ```
+/// 
+/// ```compile_fail,E0308
+/// # use godot_macros::*;
+/// # #[derive(Debug, Default, PartialEq)] struct Node;
+/// # #[derive(Debug, Default, PartialEq)] struct Player;
+/// # struct Base;
+/// # impl Base {
+/// #     fn try_get_node_as<T: Default>(&self, node_path: &str) -> Option<T> { (node_path == "Player").then(T::default) }
+/// # }
+/// # struct Main { base: Base }
+/// # impl Main {
+/// #     fn base(&self) -> &Base { &self.base }
+/// #     fn ready(&mut self) {
+/// let player: Player = try_n!(self, Player, "Player"); // Fails to compile as the node might not exist
+/// #     }
+/// # }
+/// ```
+#[macro_export]
+macro_rules! try_n {
+    ($self:ident, $node_path:expr) => {
+        $self.base().try_get_node_as::<Node>($node_path)
+    };
+    ($self:ident, $node_type:ty, $node_path:expr) => {
+        $self.base().try_get_node_as::<$node_type>($node_path)
+    };
+    ($self:ident, $node_path:expr, $node_type:ty) => {
+        $self.base().try_get_node_as::<$node_type>($node_path)
+    };
+}
+
+/// Macro for quickly getting a mutable reference to a node from its path without panicking. Can choose to specify node type, otherwise defaults to Node.
+///
+/// Returns None if node_path cannot be found or the node is not of the given type.
+///
+/// # Example
+///
+/// ```ignore
+/// let mut player: Option<Gd<Node>> = try_nm!(self, "Player"); // Mutable reference to a child Node named "Player", if it exists
+/// let mut player: Option<Gd<Player>> = try_nm!(self, Player, "Player"); // Mutable reference to a child Player named "Player", if it exists
+/// let mut player: Option<Gd<Player>> = try_nm!(self, "Player", Player); // Same but with type and name switched
+///
+/// // Skip the player if it was freed earlier this frame
+/// if let Some(mut player) = try_nm!(self, "Player") {
+///     player.set_process(false);
+/// }
+///
+/// // Expanded
+/// let mut player: Option<Gd<Node>> = self.base_mut().try_get_node_as::<Node>("Player");
+/// let mut player: Option<Gd<Player>> = self.base_mut().try_get_node_as::<Player>("Player");
+/// ```
+#[macro_export]
+macro_rules! try_nm {
+    ($self:ident, $node_path:expr) => {
+        $self.base_mut().try_get_node_as::<Node>($node_path)
+    };
+    ($self:ident, $node_type:ty, $node_path:expr) => {
+        $self.base_mut().try_get_node_as::<$node_type>($node_path)
+    };
+    ($self:ident, $node_path:expr, $node_type:ty) => {
+        $self.base_mut().try_get_node_as::<$node_type>($node_path)
+    };
+}
+
+/// Macro for finding a descendant of self whose name matches the given pattern, which can use Godot's * and ? wildcards.
+/// Can choose to specify node type, otherwise defaults to Node. Can also choose whether to search recursively, otherwise it does.
+/// 
+/// # Panics
+/// 
+/// Panics if no matching node can be found, or the node is not of the given type.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// let player: Gd<Node> = find!(self, "Player*"); // First descendant Node whose name starts with "Player"
+/// let player: Gd<Player> = find!(self, Player, "Player*"); // Same but cast to Player
+/// let player: Gd<Node> = find!(self, "Player*", recursive: false); // Only search direct children
+/// let player: Gd<Player> = find!(self, Player, "Player*", recursive: false); // Same but cast to Player
+/// 
+/// // Expanded
+/// let player: Gd<Node> = self.base().find_child("Player*")
+///     .unwrap_or_else(|| panic!("Node '{}' has no child matching {}", self.base().get_name(), "\"Player*\""));
+/// let player: Gd<Player> = self.base().find_child("Player*")
+///     .unwrap_or_else(|| panic!("Node '{}' has no child matching {}", self.base().get_name(), "\"Player*\""))
+///     .cast::<Player>();
+/// let player: Gd<Node> = self.base().find_child_ex("Player*").recursive(false).done()
+///     .unwrap_or_else(|| panic!("Node '{}' has no child matching {}", self.base().get_name(), "\"Player*\""));
+/// ```
+#[macro_export]
+macro_rules! find {
+    ($self:ident, $pattern:expr) => {
+        $self.base().find_child($pattern)
+            .unwrap_or_else(|| panic!("Node '{}' has no child matching {}", $self.base().get_name(), stringify!($pattern)))
+    };
+    ($self:ident, $pattern:expr, recursive: $recursive:expr) => {
+        $self.base().find_child_ex($pattern).recursive($recursive).done()
+            .unwrap_or_else(|| panic!("Node '{}' has no child matching {}", $self.base().get_name(), stringify!($pattern)))
+    };
+    ($self:ident, $node_type:ty, $pattern:expr) => {
+        find!($self, $pattern).cast::<$node_type>()
+    };
+    ($self:ident, $node_type:ty, $pattern:expr, recursive: $recursive:expr) => {
+        find!($self, $pattern, recursive: $recursive).cast::<$node_type>()
+    };
+}
+
+/// Returns whether a node exists at the provided path relative to self.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Only look up the player if it has been spawned
+/// if has_node!(self, "Player") {
+///     let player: Gd<Player> = n!(self, Player, "Player");
+/// }
+/// 
+/// // Expanded
+/// if self.base().has_node("Player") {
+///     let player: Gd<Player> = self.base().get_node_as::<Player>("Player");
+/// }
+/// ```
+#[macro_export]
+macro_rules! has_node {
+    ($self:ident, $node_path:expr) => {
+        $self.base().has_node($node_path)
+    };
+}
+
+/// Checks a node path at compile time when used in a constant, panicking on obvious mistakes. Used by strict_n! and strict_nm!.
+/// 
+/// # Panics
+/// 
+/// Panics if the path is empty, or starts or ends with whitespace.
+/// 
+/// # Example
+/// 
+/// ```
+/// const _: () = godot_macros::check_node_path("Player/Weapon"); // Compiles
+/// ```
+/// 
+/// ```compile_fail
+/// const _: () = godot_macros::check_node_path("Player "); // Fails to compile due to the trailing space
+/// ```
+#[doc(hidden)]
+pub const fn check_node_path(node_path: &str) {
+    let bytes = node_path.as_bytes();
+    if bytes.is_empty() {
+        panic!("Node path is empty");
+    }
+    if bytes[0].is_ascii_whitespace() {
+        panic!("Node path starts with whitespace");
+    }
+    if bytes[bytes.len() - 1].is_ascii_whitespace() {
+        panic!("Node path ends with whitespace");
+    }
+}
+
+/// Same as n!, but the node path must be a string literal, which is checked for obvious mistakes at compile time.
+/// Use n! for paths that are built at runtime.
+/// 
+/// # Panics
+/// 
+/// Panics if node_path cannot be found. Fails to compile if node_path is empty, or starts or ends with whitespace.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// let label: Gd<Node> = strict_n!(self, "Player"); // Reference to a child Node named "Player"
+/// let label: Gd<Label> = strict_n!(self, Label, "Player"); // Reference to a child Label named "Player"
+/// let label: Gd<Label> = strict_n!(self, "Player", Label); // Same but with type and name switched
+/// 
+/// // Expanded
+/// let label: Gd<Node> = {
+///     const _: () = godot_macros::check_node_path("Player");
+///     n!(self, "Player")
+/// };
+/// ```
+/// 
+/// ```compile_fail,E0080
+/// # use godot_macros::*;
+/// # #[derive(Default)] struct Node;
+/// # struct Base;
+/// # impl Base { fn get_node_as<T: Default>(&self, _node_path: &str) -> T { T::default() } }
+/// # struct Player { base: Base }
+/// # impl Player {
+/// #     fn base(&self) -> &Base { &self.base }
+/// #     fn ready(&mut self) {
+/// let label: Node = strict_n!(self, ""); // Fails to compile as the path is empty
+/// #     }
+/// # }
+/// ```
+#[macro_export]
+macro_rules! strict_n {
+    ($self:ident, $node_path:literal) => {{
+        const _: () = $crate::check_node_path($node_path);
+        n!($self, $node_path)
+    }};
+    ($self:ident, $node_type:ty, $node_path:literal) => {{
+        const _: () = $crate::check_node_path($node_path);
+        n!($self, $node_type, $node_path)
+    }};
+    ($self:ident, $node_path:literal, $node_type:ty) => {{
+        const _: () = $crate::check_node_path($node_path);
+        n!($self, $node_type, $node_path)
+    }};
+}
+
+/// Same as nm!, but the node path must be a string literal, which is checked for obvious mistakes at compile time.
+/// Use nm! for paths that are built at runtime.
+/// 
+/// # Panics
+/// 
+/// Panics if node_path cannot be found. Fails to compile if node_path is empty, or starts or ends with whitespace.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// let mut label: Gd<Node> = strict_nm!(self, "Player"); // Mutable reference to a child Node named "Player"
+/// let mut label: Gd<Label> = strict_nm!(self, Label, "Player"); // Mutable reference to a child Label named "Player"
+/// let mut label: Gd<Label> = strict_nm!(self, "Player", Label); // Same but with type and name switched
+/// 
+/// // Expanded
+/// let mut label: Gd<Node> = {
+///     const _: () = godot_macros::check_node_path("Player");
+///     nm!(self, "Player")
+/// };
+/// ```
+/// 
+/// ```compile_fail,E0080
+/// # use godot_macros::*;
+/// # #[derive(Default)] struct Node;
+/// # struct Base;
+/// # impl Base { fn get_node_as<T: Default>(&self, _node_path: &str) -> T { T::default() } }
+/// # struct Player { base: Base }
+/// # impl Player {
+/// #     fn base_mut(&mut self) -> &mut Base { &mut self.base }
+/// #     fn ready(&mut self) {
+/// let mut label: Node = strict_nm!(self, " Player"); // Fails to compile due to the leading space
+/// #     }
+/// # }
+/// ```
+#[macro_export]
+macro_rules! strict_nm {
+    ($self:ident, $node_path:literal) => {{
+        const _: () = $crate::check_node_path($node_path);
+        nm!($self, $node_path)
+    }};
+    ($self:ident, $node_type:ty, $node_path:literal) => {{
+        const _: () = $crate::check_node_path($node_path);
+        nm!($self, $node_type, $node_path)
+    }};
+    ($self:ident, $node_path:literal, $node_type:ty) => {{
+        const _: () = $crate::check_node_path($node_path);
+        nm!($self, $node_type, $node_path)
+    }};
+}
+
+/// Macro for getting a reference to a node from its path, caching it in the provided Option\<Gd\<T\>\> field of self so
+/// the tree is only searched the first time. Can choose to specify node type, otherwise defaults to Node.
+/// 
+/// Note: The cache is not invalidated automatically. If the node is freed, set the field back to None.
+/// 
+/// # Panics
+/// 
+/// Panics if the field is empty and node_path cannot be found.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Field on self: player: Option<Gd<Player>>
+/// let player: Gd<Player> = cached_n!(self, player, Player, "Player"); // Searches the tree and fills self.player
+/// let player: Gd<Player> = cached_n!(self, player, Player, "Player"); // Reuses self.player
+/// 
+/// // Expanded
+/// let player: Gd<Player> = {
+///     if self.player.is_none() {
+///         let node = self.base().get_node_as::<Player>("Player");
+///         self.player = Some(node);
+///     }
+///     self.player.clone().unwrap()
+/// };
+/// ```
+/// 
+/// ```
+/// # use godot_macros::*;
+/// # use std::cell::Cell;
+/// # #[derive(Clone, Default)] struct Player;
+/// # struct Base { lookups: Cell<u32> }
+/// # impl Base {
+/// #     fn get_node_as<T: Default>(&self, _node_path: &str) -> T { self.lookups.set(self.lookups.get() + 1); T::default() }
+/// # }
+/// # struct Main { base: Base, player: Option<Player> }
+/// # impl Main {
+/// #     fn base(&self) -> &Base { &self.base }
+/// #     fn process(&mut self) {
+/// for _frame in 0..3 {
+///     let _player: Player = cached_n!(self, player, Player, "Player");
+/// }
+/// assert!(self.player.is_some());
+/// assert_eq!(self.base.lookups.get(), 1); // Only searched the tree on the first frame
+/// 
+/// // Clearing the field after the node is freed searches the tree again
+/// self.player = None;
+/// let _player: Player = cached_n!(self, player, Player, "Player");
+/// assert_eq!(self.base.lookups.get(), 2);
+/// #     }
+/// # }
+/// # Main { base: Base { lookups: Cell::new(0) }, player: None }.process();
+/// ```
+#[macro_export]
+macro_rules! cached_n {
+    ($self:ident, $cache_field:ident, $node_path:expr) => {
+        cached_n!($self, $cache_field, Node, $node_path)
+    };
+    ($self:ident, $cache_field:ident, $node_type:ty, $node_path:expr) => {{
+        if $self.$cache_field.is_none() {
+            let node = $self.base().get_node_as::<$node_type>($node_path);
+            $self.$cache_field = Some(node);
+        }
+        $self.$cache_field.clone().unwrap()
+    }};
+}
+
+/// Macro for getting a reference to a node from its path through Godot's get_node_or_null, which does not push an error
+/// when node_path cannot be found. Can choose to specify node type, otherwise defaults to Node.
+/// 
+/// Note: gdext represents Godot's null as None, so unlike in GDScript this returns an Option and behaves the same as
+/// try_n!. It is provided for code that mirrors Godot's own API names.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// let player: Option<Gd<Node>> = node_or_null!(self, "Player"); // Reference to a child Node named "Player", if it exists
+/// let player: Option<Gd<Player>> = node_or_null!(self, Player, "Player"); // Same but only if it is a Player
+/// 
+/// // Expanded
+/// let player: Option<Gd<Node>> = self.base().get_node_or_null("Player");
+/// let player: Option<Gd<Player>> = self.base().get_node_or_null("Player").and_then(|node| node.try_cast::<Player>().ok());
+/// ```
+#[macro_export]
+macro_rules! node_or_null {
+    ($self:ident, $node_path:expr) => {
+        $self.base().get_node_or_null($node_path)
+    };
+    ($self:ident, $node_type:ty, $node_path:expr) => {
+        $self.base().get_node_or_null($node_path).and_then(|node| node.try_cast::<$node_type>().ok())
+    };
+}
+
+/// Macro for quickly getting a non-mutable reference to a node from its scene-unique name, without the % prefix. Can choose to specify node type, otherwise defaults to Node.
+///
+/// Note: Unique name must be a string literal, as the % is prepended at compile time.
+///
+/// # Panics
+///
+/// Panics if no node with the unique name can be found.
+///
+/// # Example
+///
+/// ```ignore
+/// let camera: Gd<Node> = uniq!(self, "PlayerCamera"); // Reference to a Node with the unique name "PlayerCamera"
+/// let camera: Gd<Camera2D> = uniq!(self, Camera2D, "PlayerCamera"); // Reference to a Camera2D with the unique name "PlayerCamera"
+/// let camera: Gd<Camera2D> = uniq!(self, "PlayerCamera", Camera2D); // Same but with type and name switched
+///
+/// // Expanded
+/// let camera: Gd<Node> = self.base().get_node_as::<Node>("%PlayerCamera");
+/// let camera: Gd<Camera2D> = self.base().get_node_as::<Camera2D>("%PlayerCamera");
+/// ```
+#[macro_export]
+macro_rules! uniq {
+    ($self:ident, $unique_name:literal) => {
+        $self.base().get_node_as::<Node>(concat!("%", $unique_name))
+    };
+    ($self:ident, $node_type:ty, $unique_name:literal) => {
+        $self.base().get_node_as::<$node_type>(concat!("%", $unique_name))
+    };
+    ($self:ident, $unique_name:literal, $node_type:ty) => {
+        $self.base().get_node_as::<$node_type>(concat!("%", $unique_name))
+    };
+}
+
+/// Macro for quickly getting a reference to an autoload from its name. Can choose to specify node type, otherwise defaults to Node.
+/// 
+/// Note: Autoload name must be a string literal, as the /root/ prefix is prepended at compile time.
+/// 
+/// # Panics
+/// 
+/// Panics if no autoload with the name can be found.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// let game_state: Gd<Node> = autoload!(self, "GameState"); // Reference to the autoload named "GameState"
+/// let game_state: Gd<GameState> = autoload!(self, GameState, "GameState"); // Same but cast to GameState
+/// 
+/// // Read the high score from the GameState autoload
+/// let high_score: u32 = autoload!(self, GameState, "GameState").bind().high_score;
+/// 
+/// // Expanded
+/// let game_state: Gd<Node> = self.base().get_node_as::<Node>("/root/GameState");
+/// let game_state: Gd<GameState> = self.base().get_node_as::<GameState>("/root/GameState");
+/// ```
+#[macro_export]
+macro_rules! autoload {
+    ($self:ident, $autoload_name:literal) => {
+        $self.base().get_node_as::<Node>(concat!("/root/", $autoload_name))
+    };
+    ($self:ident, $node_type:ty, $autoload_name:literal) => {
+        $self.base().get_node_as::<$node_type>(concat!("/root/", $autoload_name))
+    };
+}
+
+/// Simplifies connecting a signal on a node to to a callback on either self or a second provided node.
+/// 
+/// Note: Callback function must be registered as a function with Godot, either in GDScript or by #\[func\].
+/// 
+/// # Panics
+/// 
+/// Panics if any of the named strings do not correspond to anything in the engine.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Connect MobDetector.body_entered -> self.on_body_entered
+/// connect!(self, "MobDetector", "body_entered", "on_body_entered");
+/// // Expanded
+/// n!(self, "MobDetector").connect("body_entered", &self.base().callable("on_body_entered"));
+/// 
+/// // Connect MobDetector.body_entered -> Ui.game_over
+/// connect!(self, "MobDetector", "body_entered", "Ui", "game_over");
+/// // Expanded
+/// n!(self, "MobDetector").connect("body_entered", &n!(self, "Ui").callable("game_over"));
+/// 
+/// // Connect MobDetector.body_entered -> self.on_body_entered, deferred to idle time
+/// connect!(self, "MobDetector", "body_entered", "on_body_entered", flags: ConnectFlags::DEFERRED);
+/// // Expanded
+/// n!(self, "MobDetector").connect_flags("body_entered", &self.base().callable("on_body_entered"), ConnectFlags::DEFERRED);
+/// 
+/// // Connect LevelGoal.body_entered -> Ui.level_complete, disconnecting after the first emission
+/// connect!(self, "LevelGoal", "body_entered", "Ui", "level_complete", flags: ConnectFlags::ONE_SHOT);
+/// // Expanded
+/// n!(self, "LevelGoal").connect_flags("body_entered", &n!(self, "Ui").callable("level_complete"), ConnectFlags::ONE_SHOT);
+/// ```
+#[macro_export]
+macro_rules! connect {
+    ($self:ident, $node_path:expr, $signal:expr, $callback_name:expr, flags: $flags:expr) => {
+        n!($self, $node_path).connect_flags($signal, &$self.base().callable($callback_name), $flags)
+    };
+    ($self:ident, $node_path_1:expr, $signal:expr, $node_path_2:expr, $callback_name:expr, flags: $flags:expr) => {
+        n!($self, $node_path_1).connect_flags($signal, &n!($self, $node_path_2).callable($callback_name), $flags)
+    };
+    ($self:ident, $node_path:expr, $signal:expr, $callback_name:expr) => {
+        n!($self, $node_path).connect($signal, &$self.base().callable($callback_name))
+    };
+    ($self:ident, $node_path_1:expr, $signal:expr, $node_path_2:expr, $callback_name:expr) => {
+        n!($self, $node_path_1).connect($signal, &n!($self, $node_path_2).callable($callback_name))
+    };
+}
+
+/// Simplifies disconnecting a signal on a node from a callback on either self or a second provided node. Takes the same arguments as connect!.
+///
+/// # Panics
+///
+/// Panics if any of the named strings do not correspond to anything in the engine.
+///
+/// # Example
+///
+/// ```ignore
+/// // Disconnect MobDetector.body_entered -> self.on_body_entered
+/// disconnect!(self, "MobDetector", "body_entered", "on_body_entered");
+/// // Expanded
+/// n!(self, "MobDetector").disconnect("body_entered", &self.base().callable("on_body_entered"));
+///
+/// // Disconnect MobDetector.body_entered -> Ui.game_over
+/// disconnect!(self, "MobDetector", "body_entered", "Ui", "game_over");
+/// // Expanded
+/// n!(self, "MobDetector").disconnect("body_entered", &n!(self, "Ui").callable("game_over"));
+/// ```
+#[macro_export]
+macro_rules! disconnect {
+    ($self:ident, $node_path:expr, $signal:expr, $callback_name:expr) => {
+        n!($self, $node_path).disconnect($signal, &$self.base().callable($callback_name))
+    };
+    ($self:ident, $node_path_1:expr, $signal:expr, $node_path_2:expr, $callback_name:expr) => {
+        n!($self, $node_path_1).disconnect($signal, &n!($self, $node_path_2).callable($callback_name))
+    };
+}
+
+/// Same as connect!, but returns a Result instead of the raw Error code, so failed connections are not silently ignored.
+/// The Err contains a message naming the signal, the callback, and the Error returned by Godot.
+/// 
+/// # Panics
+/// 
+/// Panics if either of the node paths cannot be found.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Connect MobDetector.body_entered -> self.on_body_entered
+/// connect_checked!(self, "MobDetector", "body_entered", "on_body_entered")?;
+/// 
+/// // Connect MobDetector.body_entered -> Ui.game_over, logging any failure
+/// if let Err(message) = connect_checked!(self, "MobDetector", "body_entered", "Ui", "game_over") {
+///     godot_error!("{}", message); // Failed to connect signal "body_entered" on "MobDetector" to "game_over" on "Ui": ...
+/// }
+/// 
+/// // Expanded
+/// match connect!(self, "MobDetector", "body_entered", "on_body_entered") {
+///     Error::OK => Ok(()),
+///     error => Err(format!("Failed to connect signal {} on {} to {}: {:?}", "\"body_entered\"", "\"MobDetector\"", "\"on_body_entered\"", error)),
+/// }?;
+/// ```
+/// 
+/// ```
+/// # use godot_macros::*;
+/// # #[allow(non_camel_case_types)]
+/// # #[derive(Debug)] enum Error { OK, ERR_INVALID_PARAMETER }
+/// # struct Callable;
+/// # struct Node;
+/// # impl Node {
+/// #     fn connect(&mut self, signal: &str, _callable: &Callable) -> Error {
+/// #         if signal == "body_entered" { Error::OK } else { Error::ERR_INVALID_PARAMETER }
+/// #     }
+/// #     fn callable(&self, _method: &str) -> Callable { Callable }
+/// # }
+/// # struct Base;
+/// # impl Base {
+/// #     fn get_node_as<T>(&self, _node_path: &str) -> Node { Node }
+/// #     fn callable(&self, _method: &str) -> Callable { Callable }
+/// # }
+/// # struct Player { base: Base }
+/// # impl Player {
+/// #     fn base(&self) -> &Base { &self.base }
+/// #     fn ready(&mut self) {
+/// // MobDetector only has a body_entered signal
+/// assert_eq!(connect_checked!(self, "MobDetector", "body_entered", "on_body_entered"), Ok(()));
+/// assert_eq!(
+///     connect_checked!(self, "MobDetector", "body_exited", "on_body_exited"),
+///     Err(r#"Failed to connect signal "body_exited" on "MobDetector" to "on_body_exited": ERR_INVALID_PARAMETER"#.to_string())
+/// );
+/// assert_eq!(
+///     connect_checked!(self, "MobDetector", "body_exited", "Ui", "game_over"),
+///     Err(r#"Failed to connect signal "body_exited" on "MobDetector" to "game_over" on "Ui": ERR_INVALID_PARAMETER"#.to_string())
+/// );
+/// #     }
+/// # }
+/// # Player { base: Base }.ready();
+/// ```
+#[macro_export]
+macro_rules! connect_checked {
+    ($self:ident, $node_path:expr, $signal:expr, $callback_name:expr) => {
+        match connect!($self, $node_path, $signal, $callback_name) {
+            Error::OK => Ok(()),
+            error => Err(format!(
+                "Failed to connect signal {} on {} to {}: {:?}",
+                stringify!($signal), stringify!($node_path), stringify!($callback_name), error
+            )),
+        }
+    };
+    ($self:ident, $node_path_1:expr, $signal:expr, $node_path_2:expr, $callback_name:expr) => {
+        match connect!($self, $node_path_1, $signal, $node_path_2, $callback_name) {
+            Error::OK => Ok(()),
+            error => Err(format!(
+                "Failed to connect signal {} on {} to {} on {}: {:?}",
+                stringify!($signal), stringify!($node_path_1), stringify!($callback_name), stringify!($node_path_2), error
+            )),
+        }
+    };
+}
+
+/// Connects a signal to a method on self using gdext's typed signal API, so mismatches between the signal's parameters
+/// and the method's are caught at compile time. Connects either a signal declared on self, or a signal on a child node
+/// of the given type. Falls back to connect! when the node paths, signal and callback are all given as string literals.
+/// 
+/// Note: The method is passed as a Rust function, so it does not need to be registered with #\[func\].
+/// 
+/// # Panics
+/// 
+/// Panics if node_path cannot be found or is not of the given type.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Connect self.hit -> self.on_hit, where hit is declared with #[signal] on self
+/// connect_typed!(self, hit, on_hit);
+/// // Expanded
+/// self.signals().hit().connect_self(Self::on_hit);
+/// 
+/// // Connect MobDetector.body_entered -> self.on_body_entered
+/// connect_typed!(self, Area2D, "MobDetector", body_entered, on_body_entered);
+/// // Expanded
+/// n!(self, Area2D, "MobDetector").signals().body_entered().connect_other(&self.to_gd(), Self::on_body_entered);
+/// 
+/// // Connect MobDetector.body_entered -> Ui.game_over, where the types are unknown
+/// connect_typed!(self, "MobDetector", "body_entered", "Ui", "game_over");
+/// // Expanded
+/// connect!(self, "MobDetector", "body_entered", "Ui", "game_over");
+/// ```
+/// 
+/// ```
+/// # use godot_macros::*;
+/// # struct Error;
+/// # struct Callable;
+/// # struct Node;
+/// # impl Node { fn connect(&mut self, _signal: &str, _callable: &Callable) -> Error { Error } }
+/// # struct Base;
+/// # impl Base {
+/// #     fn get_node_as<T>(&self, _node_path: &str) -> Node { Node }
+/// #     fn callable(&self, _method: &str) -> Callable { Callable }
+/// # }
+/// # struct HitSignal;
+/// # impl HitSignal { fn connect_self(&mut self, _function: fn(&mut Player)) {} }
+/// # struct HealthChangedSignal;
+/// # impl HealthChangedSignal { fn connect_self(&mut self, _function: fn(&mut Player, i64, i64)) {} }
+/// # struct Signals;
+/// # impl Signals {
+/// #     fn hit(&mut self) -> HitSignal { HitSignal }
+/// #     fn health_changed(&mut self) -> HealthChangedSignal { HealthChangedSignal }
+/// # }
+/// # struct Player { base: Base }
+/// # impl Player {
+/// #     fn base(&self) -> &Base { &self.base }
+/// #     fn signals(&mut self) -> Signals { Signals }
+/// // Player declares #[signal] fn hit() and #[signal] fn health_changed(old: i64, new: i64)
+/// fn on_hit(&mut self) {}
+/// fn on_health_changed(&mut self, _old: i64, _new: i64) {}
+/// #     fn ready(&mut self) {
+/// connect_typed!(self, hit, on_hit);
+/// connect_typed!(self, health_changed, on_health_changed);
+/// connect_typed!(self, "MobDetector", "body_entered", "on_body_entered"); // Falls back to connect!
+/// #     }
+/// # }
+/// ```
+/// 
+/// ```compile_fail,E0308
+/// # use godot_macros::*;
+/// # struct HealthChangedSignal;
+/// # impl HealthChangedSignal { fn connect_self(&mut self, _function: fn(&mut Player, i64, i64)) {} }
+/// # struct Signals;
+/// # impl Signals { fn health_changed(&mut self) -> HealthChangedSignal { HealthChangedSignal } }
+/// # struct Player;
+/// # impl Player {
+/// #     fn signals(&mut self) -> Signals { Signals }
+/// fn on_health_changed(&mut self, _new: i64) {}
+/// #     fn ready(&mut self) {
+/// connect_typed!(self, health_changed, on_health_changed); // Fails to compile as the method is missing a parameter
+/// #     }
+/// # }
+/// ```
+#[macro_export]
+macro_rules! connect_typed {
+    ($self:ident, $signal:ident, $callback:ident) => {
+        $self.signals().$signal().connect_self(Self::$callback)
+    };
+    ($self:ident, $node_type:ty, $node_path:expr, $signal:ident, $callback:ident) => {
+        n!($self, $node_type, $node_path).signals().$signal().connect_other(&$self.to_gd(), Self::$callback)
+    };
+    ($self:ident, $($arg:literal),+, flags: $flags:expr) => {
+        connect!($self, $($arg),+, flags: $flags)
+    };
+    ($self:ident, $($arg:literal),+) => {
+        connect!($self, $($arg),+)
+    };
+}
+
+/// Returns whether the provided node has a signal with the provided name.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Only connect to the plugin's signal if it has one
+/// if has_signal!(n!(self, "Plugin"), "activated") {
+///     connect!(self, "Plugin", "activated", "on_plugin_activated");
+/// }
+/// 
+/// // Expanded
+/// if n!(self, "Plugin").has_signal("activated") {
+///     connect!(self, "Plugin", "activated", "on_plugin_activated");
+/// }
+/// ```
+#[macro_export]
+macro_rules! has_signal {
+    ($node:expr, $signal:expr) => {
+        $node.has_signal($signal)
+    };
+}
+
+/// Returns whether any input event has been pressed.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // See if player wants to continue game
+/// let continue: bool = any_press!();
+/// 
+/// // Expanded
+/// let continue: bool = Input::singleton().is_anything_pressed();
+/// ```
+#[macro_export]
+macro_rules! any_press {
+    () => {
+        Input::singleton().is_anything_pressed()
+    };
+}
+
+/// Returns whether the provided key has been pressed.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // See if player jumped
+/// let should_jump: bool = key_press!(Key::UP);
+/// 
+/// // Expanded
+/// let should_jump: bool = Input::singleton().is_key_pressed(Key::UP);
+/// ```
+#[macro_export]
+macro_rules! key_press {
+    ($keycode:expr) => {
+        Input::singleton().is_key_pressed($keycode)
+    };
+}
+
+/// Returns whether the provided key has been pressed physically.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // See if player jumped
+/// let should_jump: bool = key_press_phys!(Key::UP);
+/// 
+/// // Expanded
+/// let should_jump: bool = Input::singleton().is_physical_key_pressed(Key::UP);
+/// ```
+#[macro_export]
+macro_rules! key_press_phys {
+    ($keycode:expr) => {
+        Input::singleton().is_physical_key_pressed($keycode)
+    };
+}
+
+/// Returns whether the provided key's label has been pressed.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // See if player jumped
+/// let should_jump: bool = key_press_label!(Key::UP);
+/// 
+/// // Expanded
+/// let should_jump: bool = Input::singleton().is_key_label_pressed(Key::UP);
+/// ```
+#[macro_export]
+macro_rules! key_press_label {
+    ($keycode:expr) => {
+        Input::singleton().is_key_label_pressed($keycode)
+    };
+}
+
+/// Returns whether the provided mouse button has been pressed.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // See if player is shooting
+/// let shooting: bool = mouse_press!(MouseButton::LEFT);
+/// 
+/// // Expanded
+/// let shooting: bool = Input::singleton().is_mouse_button_pressed(MouseButton::LEFT);
+/// ```
+#[macro_export]
+macro_rules! mouse_press {
+    ($button:expr) => {
+        Input::singleton().is_mouse_button_pressed($button)
+    };
+}
+
+/// Returns the position of the mouse. Passing self returns the position relative to the node's viewport, which is
+/// what UI and in-game logic usually want. Passing global returns the position relative to the screen, ignoring
+/// any windows or viewports.
+/// 
+/// # Panics
+/// 
+/// Panics if self is passed and the current node has no viewport.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// let cursor: Vector2 = mouse_pos!(self); // Position of the mouse in the node's viewport
+/// let cursor: Vector2i = mouse_pos!(global); // Position of the mouse on the screen
+/// 
+/// // Expanded
+/// let cursor: Vector2 = self.base().get_viewport().unwrap_or_else(|| panic!("Node '{}' has no viewport", self.base().get_name())).get_mouse_position();
+/// let cursor: Vector2i = DisplayServer::singleton().mouse_get_position();
+/// ```
+#[macro_export]
+macro_rules! mouse_pos {
+    (global) => {
+        DisplayServer::singleton().mouse_get_position()
+    };
+    ($self:ident) => {
+        $self.base().get_viewport().unwrap_or_else(|| panic!("Node '{}' has no viewport", $self.base().get_name())).get_mouse_position()
+    };
+}
+
+/// Moves the mouse to the provided position in the focused window's viewport. Passing self and center instead moves the mouse to the center of the node's viewport.
+/// 
+/// # Panics
+/// 
+/// Panics if self and center are passed and the current node has no viewport.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Move the cursor to the top left corner
+/// warp_mouse!(Vector2::ZERO);
+/// // Recenter the cursor for mouselook
+/// warp_mouse!(self, center);
+/// 
+/// // Expanded
+/// Input::singleton().warp_mouse(Vector2::ZERO);
+/// Input::singleton().warp_mouse(
+///     self.base().get_viewport().unwrap_or_else(|| panic!("Node '{}' has no viewport", self.base().get_name())).get_visible_rect().size / 2.0
+/// );
+/// ```
+#[macro_export]
+macro_rules! warp_mouse {
+    ($self:ident, center) => {
+        Input::singleton().warp_mouse(
+            $self.base().get_viewport().unwrap_or_else(|| panic!("Node '{}' has no viewport", $self.base().get_name())).get_visible_rect().size / 2.0
+        )
+    };
+    ($position:expr) => {
+        Input::singleton().warp_mouse($position)
+    };
+}
+
+/// Sets the mouse mode to the provided MouseMode, or returns the current MouseMode if none is provided.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Capture the cursor for first-person controls
+/// mouse_mode!(MouseMode::CAPTURED);
+/// // Release the cursor when the pause menu opens
+/// if mouse_mode!() == MouseMode::CAPTURED {
+///     mouse_mode!(MouseMode::VISIBLE);
+/// }
+/// 
+/// // Expanded
+/// Input::singleton().set_mouse_mode(MouseMode::CAPTURED);
+/// if Input::singleton().get_mouse_mode() == MouseMode::CAPTURED {
+///     Input::singleton().set_mouse_mode(MouseMode::VISIBLE);
+/// }
+/// ```
+#[macro_export]
+macro_rules! mouse_mode {
+    () => {
+        Input::singleton().get_mouse_mode()
+    };
+    ($mode:expr) => {
+        Input::singleton().set_mouse_mode($mode)
+    };
+}
+
+/// Returns whether the provided joypad button on the provided gontroller has been pressed.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // See if player 1 jumped
+/// let should_jump: bool = joy_press!(0, JoyButton::A);
+/// 
+/// // Expanded
+/// let should_jump: bool = Input::singleton().is_joy_button_pressed(0, JoyButton::A);
+/// ```
+#[macro_export]
+macro_rules! joy_press {
+    ($device:expr, $button:expr) => {
+        Input::singleton().is_joy_button_pressed($device, $button)
+    };
+}
+
+/// Returns the position of the provided joypad axis on the provided controller, between -1.0 and 1.0.
+/// 
+/// Note: Devices are indexed from 0 in the order they were connected.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Steer player 1's car with the left stick
+/// let steering: f32 = joy_axis!(0, JoyAxis::LEFT_X);
+/// 
+/// // Expanded
+/// let steering: f32 = Input::singleton().get_joy_axis(0, JoyAxis::LEFT_X);
+/// ```
+#[macro_export]
+macro_rules! joy_axis {
+    ($device:expr, $axis:expr) => {
+        Input::singleton().get_joy_axis($device, $axis)
+    };
+}
+
+/// Starts vibrating the provided controller with the given weak and strong motor magnitudes, between 0.0 and 1.0.
+/// Can choose a duration in seconds, otherwise vibrates until stopped with vibrate_stop!.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Rumble player 1's controller when they get hit
+/// vibrate!(0, 0.5, 0.8, 0.3);
+/// // Rumble until the player lets go of the chainsaw
+/// vibrate!(0, 1.0, 1.0);
+/// 
+/// // Expanded
+/// Input::singleton().start_joy_vibration_ex(0, 0.5, 0.8).duration(0.3).done();
+/// Input::singleton().start_joy_vibration(0, 1.0, 1.0);
+/// ```
+#[macro_export]
+macro_rules! vibrate {
+    ($device:expr, $weak_magnitude:expr, $strong_magnitude:expr) => {
+        Input::singleton().start_joy_vibration($device, $weak_magnitude, $strong_magnitude)
+    };
+    ($device:expr, $weak_magnitude:expr, $strong_magnitude:expr, $duration:expr) => {
+        Input::singleton().start_joy_vibration_ex($device, $weak_magnitude, $strong_magnitude).duration($duration).done()
+    };
+}
+
+/// Stops vibrating the provided controller.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Stop rumbling once the chainsaw is let go
+/// vibrate_stop!(0);
+/// 
+/// // Expanded
+/// Input::singleton().stop_joy_vibration(0);
+/// ```
+#[macro_export]
+macro_rules! vibrate_stop {
+    ($device:expr) => {
+        Input::singleton().stop_joy_vibration($device)
+    };
+}
+
+/// Returns whether the provided action has been pressed.
+/// 
+/// # Panics
+/// 
+/// Panics if provided action is not found in the Godot.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // See if player jumped
+/// let should_jump: bool = act_press!("jump");
+/// 
+/// // Expanded
+/// let should_jump: bool = Input::singleton().is_action_pressed("jump");
+/// ```
+#[macro_export]
+macro_rules! act_press {
+    ($action:expr) => {
+        Input::singleton().is_action_pressed($action)
+    };
+}
+
+/// Returns whether the provided action has been pressed down.
+/// 
+/// # Panics
+/// 
+/// Panics if provided action is not found in the Godot.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // See if player started jumping
+/// let begun_jumping: bool = act_press_down!("jump");
+/// 
+/// // Expanded
+/// let begun_jumping: bool = Input::singleton().is_action_just_pressed("jump");
+/// ```
+#[macro_export]
+macro_rules! act_press_down {
+    ($action:expr) => {
+        Input::singleton().is_action_just_pressed($action)
+    };
+}
+
+/// Returns whether the provided action has been unpressed.
+/// 
+/// # Panics
+/// 
+/// Panics if provided action is not found in the Godot.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // See if player stopped jumping
+/// let jump_stopped: bool = act_press_up!("jump");
+/// 
+/// // Expanded
+/// let jump_stopped: bool = Input::singleton().is_action_just_released("jump");
+/// ```
+#[macro_export]
+macro_rules! act_press_up {
+    ($action:expr) => {
+        Input::singleton().is_action_just_released($action)
+    };
+}
+
+/// Returns whether each of the provided actions has been unpressed, as a fixed-size array in the same order as the actions.
+/// 
+/// # Panics
+/// 
+/// Panics if any of the provided actions is not found in Godot.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // See which abilities the player stopped using
+/// let [jump_stopped, dash_stopped, shoot_stopped]: [bool; 3] = acts_released!("jump", "dash", "shoot");
+/// 
+/// // Expanded
+/// let [jump_stopped, dash_stopped, shoot_stopped]: [bool; 3] = [
+///     Input::singleton().is_action_just_released("jump"),
+///     Input::singleton().is_action_just_released("dash"),
+///     Input::singleton().is_action_just_released("shoot"),
+/// ];
+/// ```
+#[macro_export]
+macro_rules! acts_released {
+    ($($action:expr),+ $(,)?) => {
+        [$(Input::singleton().is_action_just_released($action)),+]
+    };
+}
+
+/// Returns the strength by which the provided action is pressed down.
+/// 
+/// # Panics
+/// 
+/// Panics if provided action is not found in the Godot.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Get speed so you can speed up the more you push the stick
+/// let speed_multiplier: f32 = act_str!("move_right");
+/// 
+/// // Expanded
+/// let speed_multiplier: f32 = Input::singleton().get_action_strength("move_right");
+/// ```
+#[macro_export]
+macro_rules! act_str {
+    ($action:expr) => {
+        Input::singleton().get_action_strength($action)
+    };
+}
+
+/// Returns the raw strength by which the provided action is pressed down.
+/// 
+/// # Panics
+/// 
+/// Panics if provided action is not found in the Godot.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Get speed so you can speed up the more you push the stick
+/// let speed_multiplier: f32 = act_str_raw!("move_right");
+/// 
+/// // Expanded
+/// let speed_multiplier: f32 = Input::singleton().get_action_raw_strength("move_right");
+/// ```
+#[macro_export]
+macro_rules! act_str_raw {
+    ($action:expr) => {
+        Input::singleton().get_action_raw_strength($action)
+    };
+}
+
+/// Returns the position on an a given access between two actions.
+/// 
+/// # Panics
+/// 
+/// Panics if either of the provided actions is not found in Godot.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Get controller's Y axis
+/// let y_axis: f32 = act_axis!("move_left", "move_right");
+/// 
+/// // Expanded
+/// let y_axis: f32 = Input::singleton().get_axis("move_left", "move_right");
+/// ```
+#[macro_export]
+macro_rules! act_axis {
+    ($negative_action:expr, $positive_action:expr) => {
+        Input::singleton().get_axis($negative_action, $positive_action)
+    };
+}
+
+/// Returns the input vector between four actions, one for each direction. Can choose to specify a deadzone, otherwise uses the average of the actions' deadzones.
+/// 
+/// # Panics
+/// 
+/// Panics if any of the provided actions is not found in Godot.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Move the player in the direction being pressed
+/// let direction: Vector2 = act_axis_vec!("move_left", "move_right", "move_up", "move_down");
+/// self.base_mut().set_velocity(direction * SPEED);
+/// 
+/// // Same but ignoring small stick movements
+/// let direction: Vector2 = act_axis_vec!("move_left", "move_right", "move_up", "move_down", 0.2);
+/// 
+/// // Expanded
+/// let direction: Vector2 = Input::singleton().get_vector("move_left", "move_right", "move_up", "move_down");
+/// let direction: Vector2 = Input::singleton().get_vector_ex("move_left", "move_right", "move_up", "move_down").deadzone(0.2).done();
+/// ```
+#[macro_export]
+macro_rules! act_axis_vec {
+    ($negative_x:expr, $positive_x:expr, $negative_y:expr, $positive_y:expr) => {
+        Input::singleton().get_vector($negative_x, $positive_x, $negative_y, $positive_y)
+    };
+    ($negative_x:expr, $positive_x:expr, $negative_y:expr, $positive_y:expr, $deadzone:expr) => {
+        Input::singleton().get_vector_ex($negative_x, $positive_x, $negative_y, $positive_y).deadzone($deadzone).done()
+    };
+}
+
+/// Returns the input vector between four actions with an explicit deadzone, for controllers with stick drift.
+/// Without a deadzone it is the same as act_axis_vec!.
+/// 
+/// # Panics
+/// 
+/// Panics if any of the provided actions is not found in Godot.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Ignore stick movements smaller than 0.2
+/// let direction: Vector2 = axis_vec_dz!("move_left", "move_right", "move_up", "move_down", 0.2);
+/// // Use the actions' own deadzones
+/// let direction: Vector2 = axis_vec_dz!("move_left", "move_right", "move_up", "move_down");
+/// 
+/// // Expanded
+/// let direction: Vector2 = Input::singleton().get_vector_ex("move_left", "move_right", "move_up", "move_down").deadzone(0.2).done();
+/// let direction: Vector2 = act_axis_vec!("move_left", "move_right", "move_up", "move_down");
+/// ```
+#[macro_export]
+macro_rules! axis_vec_dz {
+    ($negative_x:expr, $positive_x:expr, $negative_y:expr, $positive_y:expr) => {
+        act_axis_vec!($negative_x, $positive_x, $negative_y, $positive_y)
+    };
+    ($negative_x:expr, $positive_x:expr, $negative_y:expr, $positive_y:expr, $deadzone:expr) => {
+        Input::singleton().get_vector_ex($negative_x, $positive_x, $negative_y, $positive_y).deadzone($deadzone).done()
+    };
+}
+
+/// Macro for quickly emitting signal with no arguments.
+/// 
+/// # Panics
+/// 
+/// Panics if the provided signal does not exist on self.
+/// 
+/// # Example
+/// ```ignore
+/// // Emit that current node has been hit
+/// emit!(self, "hit");
+/// 
+/// // Expanded
+/// self.base_mut().emit_signal("hit", &[]);
+/// ```
+#[macro_export]
+macro_rules! emit {
+    ($self:ident, $signal:expr) => {
+        $self.base_mut().emit_signal($signal, &[])
+    };
+}
+
+/// Macro for quickly emitting signal with any number of arguments, each converted to a Variant.
+///
+/// # Panics
+///
+/// Panics if the provided signal does not exist on self.
+///
+/// # Example
+/// ```ignore
+/// // Emit that current node's health has changed
+/// emit_args!(self, "health_changed", self.health);
+///
+/// // Emit that current node was hit, by whom, and how hard
+/// emit_args!(self, "hit", attacker_name, damage, is_critical);
+///
+/// // Expanded, the arguments are converted first so they can borrow self
+/// {
+///     let args = [self.health.to_variant()];
+///     self.base_mut().emit_signal("health_changed", &args)
+/// };
+/// {
+///     let args = [attacker_name.to_variant(), damage.to_variant(), is_critical.to_variant()];
+///     self.base_mut().emit_signal("hit", &args)
+/// };
+/// ```
+#[macro_export]
+macro_rules! emit_args {
+    ($self:ident, $signal:expr) => {
+        emit!($self, $signal)
+    };
+    ($self:ident, $signal:expr, $($arg:expr),+ $(,)?) => {{
+        let args = [$($arg.to_variant()),+];
+        $self.base_mut().emit_signal($signal, &args)
+    }};
+}
+
+/// Macro for emitting a typed signal declared on self with #\[signal\], so the arguments are checked against the signal's parameters at compile time.
+/// 
+/// # Example
+/// ```ignore
+/// // Emit that current node has been hit
+/// signal!(self, hit);
+/// 
+/// // Emit that current node's health has changed
+/// signal!(self, health_changed, old_health, new_health);
+/// 
+/// // Expanded
+/// self.signals().hit().emit();
+/// self.signals().health_changed().emit(old_health, new_health);
+/// ```
+#[macro_export]
+macro_rules! signal {
+    ($self:ident, $signal:ident $(, $arg:expr)* $(,)?) => {
+        $self.signals().$signal().emit($($arg),*)
+    };
+}
+
+/// Frees provided self.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Destroy self
+/// free!(self);
+/// 
+/// // Expanded
+/// self.base_mut().queue_free();
+/// ```
+#[macro_export]
+macro_rules! free {
+    ($self:ident) => {
+        $self.base_mut().queue_free()
+    };
+}
+
+/// Frees provided self through a deferred call, so self is only queued to be freed at idle time rather than immediately.
+/// 
+/// Note: Unlike free!, which queues self to be freed at the end of the frame, this first waits until idle time to queue it.
+/// It takes the same mutable borrow of self as free!.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Destroy self once the rest of this frame has been handled
+/// free_deferred!(self);
+/// 
+/// // Expanded
+/// self.base_mut().call_deferred("queue_free", &[]);
+/// ```
+#[macro_export]
+macro_rules! free_deferred {
+    ($self:ident) => {
+        $self.base_mut().call_deferred("queue_free", &[])
+    };
+}
+
+/// Reloads the scene which this node is a part of.
+/// 
+/// # Panics
+/// 
+/// Panics if the current node has no tree, or its tree cannot be reloaded. The panic message includes the name of the node.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Reload current scene
+/// reload!(self);
+/// 
+/// // Expanded
+/// self.base().get_tree().unwrap_or_else(|| panic!("Node '{}' has no tree", self.base().get_name())).reload_current_scene();
+/// ```
+/// 
+/// ```
+/// # use godot_macros::*;
+/// # struct SceneTree;
+/// # impl SceneTree { fn reload_current_scene(&mut self) {} }
+/// # struct Base;
+/// # impl Base {
+/// #     fn get_tree(&self) -> Option<SceneTree> { None }
+/// #     fn get_name(&self) -> &str { "Player" }
+/// # }
+/// # struct Player { base: Base }
+/// # impl Player { fn base(&self) -> &Base { &self.base } }
+/// // Reloading from a node that has been removed from the tree names the node in the panic
+/// let player = Player { base: Base };
+/// let payload = std::panic::catch_unwind(|| reload!(player)).unwrap_err();
+/// assert_eq!(payload.downcast_ref::<String>().unwrap(), "Node 'Player' has no tree");
+/// ```
+#[macro_export]
+macro_rules! reload {
+    ($self:ident) => {
+        $self.base().get_tree().unwrap_or_else(|| panic!("Node '{}' has no tree", $self.base().get_name())).reload_current_scene()
+    };
+}
+
+/// Loads a resource from its path. Can choose to specify resource type, otherwise defaults to Resource.
+/// 
+/// # Panics
+/// 
+/// Panics if the resource cannot be loaded or is not of the given type.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// let texture: Gd<Resource> = load!("res://icon.svg"); // Load the resource at "res://icon.svg"
+/// let scene: Gd<PackedScene> = load!(PackedScene, "res://scene.tscn"); // Load the PackedScene at "res://scene.tscn"
+/// 
+/// // Expanded
+/// let texture: Gd<Resource> = load::<Resource>("res://icon.svg");
+/// let scene: Gd<PackedScene> = load::<PackedScene>("res://scene.tscn");
+/// ```
+#[macro_export]
+macro_rules! load {
+    ($path:expr) => {
+        load::<Resource>($path)
+    };
+    ($resource_type:ty, $path:expr) => {
+        load::<$resource_type>($path)
+    };
+}
+
+/// Loads a PackedScene from its path and instantiates it. Can choose to specify the scene's root node type, otherwise defaults to Node.
+/// 
+/// # Panics
+/// 
+/// Panics if the scene cannot be loaded or its root node is not of the given type.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// let mob: Gd<Node> = instantiate!("res://mob.tscn"); // Instance of the scene at "res://mob.tscn"
+/// let mob: Gd<Mob> = instantiate!(Mob, "res://mob.tscn"); // Same but cast to Mob
+/// 
+/// // Expanded
+/// let mob: Gd<Node> = load::<PackedScene>("res://mob.tscn").instantiate_as::<Node>();
+/// let mob: Gd<Mob> = load::<PackedScene>("res://mob.tscn").instantiate_as::<Mob>();
+/// ```
+#[macro_export]
+macro_rules! instantiate {
+    ($path:expr) => {
+        load::<PackedScene>($path).instantiate_as::<Node>()
+    };
+    ($node_type:ty, $path:expr) => {
+        load::<PackedScene>($path).instantiate_as::<$node_type>()
+    };
+}
+
+/// Declares a static with the provided name that caches the PackedScene at the provided path, loading it the first
+/// time get() is called on it and returning the cached scene after that, similar to preload in GDScript.
+/// 
+/// Note: As Gd pointers cannot be shared between threads, the static is thread-local and the scene is cached per thread,
+/// so it is best only used from the main thread. The scene is kept until clear() is called or the thread exits, so call
+/// clear() before Godot shuts down, such as in ExtensionLibrary::on_level_deinit, so it is not released after the engine.
+/// 
+/// # Panics
+/// 
+/// Calling get() panics if the scene cannot be loaded.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// const_scene!(MOB_SCENE, "res://mob.tscn");
+/// 
+/// // Only loads from disk for the first mob
+/// let mob: Gd<Mob> = MOB_SCENE.get().instantiate_as::<Mob>();
+/// 
+/// // Release the scene before the engine shuts down
+/// MOB_SCENE.clear();
+/// 
+/// // Expanded
+/// thread_local! {
+///     static MOB_SCENE: (fn() -> Gd<PackedScene>, std::cell::RefCell<Option<Gd<PackedScene>>>) =
+///         const { (|| load::<PackedScene>("res://mob.tscn"), std::cell::RefCell::new(None)) };
+/// }
+/// ```
+/// 
+/// ```
+/// # use godot_macros::*;
+/// # use std::cell::Cell;
+/// # thread_local! { static LOADS: Cell<u32> = const { Cell::new(0) }; }
+/// # #[derive(Clone)] struct PackedScene;
+/// # #[derive(Clone)] struct Gd<T>(T);
+/// # fn load<T>(_path: &str) -> Gd<PackedScene> { LOADS.set(LOADS.get() + 1); Gd(PackedScene) }
+/// const_scene!(MOB_SCENE, "res://mob.tscn");
+/// 
+/// MOB_SCENE.get();
+/// MOB_SCENE.get();
+/// assert_eq!(LOADS.get(), 1); // Only loaded the first time
+/// 
+/// MOB_SCENE.clear();
+/// MOB_SCENE.get();
+/// assert_eq!(LOADS.get(), 2); // Loaded again after being cleared
+/// ```
+#[macro_export]
+macro_rules! const_scene {
+    ($vis:vis $name:ident, $path:expr) => {
+        thread_local! {
+            $vis static $name: (fn() -> Gd<PackedScene>, ::std::cell::RefCell<Option<Gd<PackedScene>>>) =
+                const { (|| load::<PackedScene>($path), ::std::cell::RefCell::new(None)) };
+        }
+    };
+}
+
+/// Methods for the statics declared by const_scene!.
+pub trait SceneCache<T> {
+    /// Returns the cached scene, loading it first if it has not been loaded on this thread yet.
+    fn get(&'static self) -> T;
+
+    /// Releases the cached scene on this thread, so the next call to get() loads it again.
+    fn clear(&'static self);
+}
+
+impl<T: Clone> SceneCache<T> for std::thread::LocalKey<(fn() -> T, std::cell::RefCell<Option<T>>)> {
+    fn get(&'static self) -> T {
+        self.with(|(load, scene)| scene.borrow_mut().get_or_insert_with(load).clone())
+    }
+
+    fn clear(&'static self) {
+        self.with(|(_, scene)| scene.take());
+    }
+}
+
+/// Prints a message to the Godot console, prefixed with the name of the node. Takes the same format arguments as format!.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Print the player's current health
+/// log!(self, "Health is now {}", health);
+/// 
+/// // Expanded
+/// godot_print!("[{}] {}", self.base().get_name(), format!("Health is now {}", health));
+/// ```
+#[macro_export]
+macro_rules! log {
+    ($self:ident, $($arg:tt)*) => {
+        godot_print!("[{}] {}", $self.base().get_name(), format!($($arg)*))
+    };
+}
+
+/// Pushes a warning to the Godot console, prefixed with the name of the node. Takes the same format arguments as format!.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Warn that the player's health is low
+/// warn!(self, "Health is low: {}", health);
+/// 
+/// // Expanded
+/// godot_warn!("[{}] {}", self.base().get_name(), format!("Health is low: {}", health));
+/// ```
+#[macro_export]
+macro_rules! warn {
+    ($self:ident, $($arg:tt)*) => {
+        godot_warn!("[{}] {}", $self.base().get_name(), format!($($arg)*))
+    };
+}
+
+/// Pushes an error to the Godot console, prefixed with the name of the node. Takes the same format arguments as format!.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Report that the player's health went out of bounds
+/// err!(self, "Health out of bounds: {}", health);
+/// 
+/// // Expanded
+/// godot_error!("[{}] {}", self.base().get_name(), format!("Health out of bounds: {}", health));
+/// ```
+#[macro_export]
+macro_rules! err {
+    ($self:ident, $($arg:tt)*) => {
+        godot_error!("[{}] {}", $self.base().get_name(), format!($($arg)*))
+    };
+}
+
+/// Adds self to the provided group. Can choose to make membership persistent, so it is saved with the scene.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Mark self as an enemy
+/// group!(self, "enemies");
+/// // Mark self as an enemy, and keep it that way when the scene is saved
+/// group!(self, "enemies", persistent);
+/// // Join the group named by a field of self
+/// group!(self, self.team.clone());
+/// 
+/// // Expanded, the group is evaluated first so it can borrow self
+/// {
+///     let group = "enemies".to_string();
+///     self.base_mut().add_to_group(&group)
+/// };
+/// {
+///     let group = "enemies".to_string();
+///     self.base_mut().add_to_group_ex(&group).persistent(true).done()
+/// };
+/// {
+///     let group = self.team.clone().to_string();
+///     self.base_mut().add_to_group(&group)
+/// };
+/// ```
+#[macro_export]
+macro_rules! group {
+    ($self:ident, $group:expr) => {{
+        let group = $group.to_string();
+        $self.base_mut().add_to_group(&group)
+    }};
+    ($self:ident, $group:expr, persistent) => {{
+        let group = $group.to_string();
+        $self.base_mut().add_to_group_ex(&group).persistent(true).done()
+    }};
+}
+
+/// Returns whether self is in the provided group.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Only take damage from the player's bullets if self is an enemy
+/// if in_group!(self, "enemies") {
+///     self.take_damage(10);
+/// }
+/// 
+/// // Expanded
+/// if self.base().is_in_group("enemies") {
+///     self.take_damage(10);
+/// }
+/// ```
+#[macro_export]
+macro_rules! in_group {
+    ($self:ident, $group:expr) => {
+        $self.base().is_in_group($group)
+    };
+}
+
+/// Removes self from the provided group.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Stop being targeted as an enemy once defeated
+/// leave_group!(self, "enemies");
+/// // Leave the group named by a field of self
+/// leave_group!(self, self.team.clone());
+/// 
+/// // Expanded, the group is evaluated first so it can borrow self
+/// {
+///     let group = "enemies".to_string();
+///     self.base_mut().remove_from_group(&group)
+/// };
+/// {
+///     let group = self.team.clone().to_string();
+///     self.base_mut().remove_from_group(&group)
+/// };
+/// ```
+#[macro_export]
+macro_rules! leave_group {
+    ($self:ident, $group:expr) => {{
+        let group = $group.to_string();
+        $self.base_mut().remove_from_group(&group)
+    }};
+}
+
+/// Returns all nodes in the provided group in the tree of the current node. Can choose to specify node type, in which case
+/// nodes that are not of the given type are skipped and the rest are returned as a Vec.
+/// 
+/// # Panics
+/// 
+/// Panics if the current node has no tree.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// let enemies: Array<Gd<Node>> = nodes_in_group!(self, "enemies"); // All Nodes in the "enemies" group
+/// let enemies: Vec<Gd<Mob>> = nodes_in_group!(self, Mob, "enemies"); // All Mobs in the "enemies" group
+/// 
+/// // Expanded
+/// let enemies: Array<Gd<Node>> = self.base().get_tree().unwrap_or_else(|| panic!("Node '{}' has no tree", self.base().get_name())).get_nodes_in_group("enemies");
+/// let enemies: Vec<Gd<Mob>> = self.base().get_tree().unwrap_or_else(|| panic!("Node '{}' has no tree", self.base().get_name())).get_nodes_in_group("enemies")
+///     .iter_shared()
+///     .filter_map(|node| node.try_cast::<Mob>().ok())
+///     .collect::<Vec<Gd<Mob>>>();
+/// ```
+#[macro_export]
+macro_rules! nodes_in_group {
+    ($self:ident, $group:expr) => {
+        $self.base().get_tree().unwrap_or_else(|| panic!("Node '{}' has no tree", $self.base().get_name())).get_nodes_in_group($group)
+    };
+    ($self:ident, $node_type:ty, $group:expr) => {
+        $self.base().get_tree().unwrap_or_else(|| panic!("Node '{}' has no tree", $self.base().get_name())).get_nodes_in_group($group)
+            .iter_shared()
+            .filter_map(|node| node.try_cast::<$node_type>().ok())
+            .collect::<Vec<Gd<$node_type>>>()
+    };
+}
+
+/// Enables or disables idle processing (the node's process function) on self.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Keep processing only while self is alive
+/// set_process!(self, self.health > 0);
+/// 
+/// // Expanded
+/// {
+///     let enable = self.health > 0;
+///     self.base_mut().set_process(enable)
+/// };
+/// ```
+#[macro_export]
+macro_rules! set_process {
+    ($self:ident, $enable:expr) => {{
+        let enable = $enable;
+        $self.base_mut().set_process(enable)
+    }};
+}
+
+/// Enables or disables physics processing (the node's physics_process function) on self.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Resume physics once respawned
+/// set_physics_process!(self, true);
+/// 
+/// // Expanded
+/// {
+///     let enable = true;
+///     self.base_mut().set_physics_process(enable)
+/// };
+/// ```
+#[macro_export]
+macro_rules! set_physics_process {
+    ($self:ident, $enable:expr) => {{
+        let enable = $enable;
+        $self.base_mut().set_physics_process(enable)
+    }};
+}
+
+/// Enables or disables input processing (the node's input function) on self.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Ignore input while a cutscene plays
+/// set_input!(self, false);
+/// 
+/// // Expanded
+/// {
+///     let enable = false;
+///     self.base_mut().set_process_input(enable)
+/// };
+/// ```
+#[macro_export]
+macro_rules! set_input {
+    ($self:ident, $enable:expr) => {{
+        let enable = $enable;
+        $self.base_mut().set_process_input(enable)
+    }};
+}
+
+/// Creates a tween on self that animates one of its properties to the target value over the given duration in seconds.
+/// Returns the Tween so easing, delays, or further steps can be chained onto it.
+/// 
+/// # Panics
+/// 
+/// Panics if self has no tree.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Fade self out over one second
+/// tween!(self, "modulate:a", 0.0, 1.0);
+/// 
+/// // Same but easing out at the end
+/// tween!(self, "modulate:a", 0.0, 1.0).set_ease(EaseType::OUT);
+/// 
+/// // Expanded
+/// {
+///     let tween = self.base_mut().create_tween();
+///     let mut tween = tween.unwrap_or_else(|| panic!("Node '{}' has no tree", self.base().get_name()));
+///     tween.tween_property(&self.to_gd(), "modulate:a", &0.0.to_variant(), 1.0);
+///     tween
+/// };
+/// ```
+#[macro_export]
+macro_rules! tween {
+    ($self:ident, $property:expr, $target:expr, $duration:expr) => {{
+        let tween = $self.base_mut().create_tween();
+        let mut tween = tween.unwrap_or_else(|| panic!("Node '{}' has no tree", $self.base().get_name()));
+        tween.tween_property(&$self.to_gd(), $property, &$target.to_variant(), $duration);
+        tween
+    }};
+}
+
+/// Creates a one-shot timer in the tree of the current node, which emits "timeout" after the given number of seconds.
+/// Can choose whether the timer keeps running while the tree is paused, otherwise it does.
+/// 
+/// # Panics
+/// 
+/// Panics if the current node has no tree, or the timer cannot be created.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Call self.on_respawn after 1.5 seconds
+/// timer!(self, 1.5).connect("timeout", &self.base().callable("on_respawn"));
+/// // Same but pausing the timer while the game is paused
+/// timer!(self, 1.5, false).connect("timeout", &self.base().callable("on_respawn"));
+/// 
+/// // Expanded
+/// self.base().get_tree().unwrap_or_else(|| panic!("Node '{}' has no tree", self.base().get_name()))
+///     .create_timer(1.5)
+///     .unwrap_or_else(|| panic!("Node '{}' could not create a timer", self.base().get_name()))
+///     .connect("timeout", &self.base().callable("on_respawn"));
+/// self.base().get_tree().unwrap_or_else(|| panic!("Node '{}' has no tree", self.base().get_name()))
+///     .create_timer_ex(1.5).process_always(false).done()
+///     .unwrap_or_else(|| panic!("Node '{}' could not create a timer", self.base().get_name()))
+///     .connect("timeout", &self.base().callable("on_respawn"));
+/// ```
+#[macro_export]
+macro_rules! timer {
+    ($self:ident, $seconds:expr) => {
+        $self.base().get_tree().unwrap_or_else(|| panic!("Node '{}' has no tree", $self.base().get_name()))
+            .create_timer($seconds)
+            .unwrap_or_else(|| panic!("Node '{}' could not create a timer", $self.base().get_name()))
+    };
+    ($self:ident, $seconds:expr, $process_always:expr) => {
+        $self.base().get_tree().unwrap_or_else(|| panic!("Node '{}' has no tree", $self.base().get_name()))
+            .create_timer_ex($seconds).process_always($process_always).done()
+            .unwrap_or_else(|| panic!("Node '{}' could not create a timer", $self.base().get_name()))
+    };
+}
+
+/// Changes the running scene, either to the scene file at the given path or to the given PackedScene.
+/// 
+/// Note: String literals are treated as file paths, any other expression is treated as a Gd\<PackedScene\>.
+/// 
+/// # Panics
+/// 
+/// Panics if the current node has no tree.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Move on to the next level
+/// change_scene!(self, "res://level2.tscn");
+/// 
+/// // Move on to an already loaded level
+/// let level: Gd<PackedScene> = load!(PackedScene, "res://level2.tscn");
+/// change_scene!(self, level);
+/// 
+/// // Expanded
+/// self.base().get_tree().unwrap_or_else(|| panic!("Node '{}' has no tree", self.base().get_name())).change_scene_to_file("res://level2.tscn");
+/// self.base().get_tree().unwrap_or_else(|| panic!("Node '{}' has no tree", self.base().get_name())).change_scene_to_packed(&level);
+/// ```
+#[macro_export]
+macro_rules! change_scene {
+    ($self:ident, $path:literal) => {
+        $self.base().get_tree().unwrap_or_else(|| panic!("Node '{}' has no tree", $self.base().get_name())).change_scene_to_file($path)
+    };
+    ($self:ident, $packed_scene:expr) => {
+        $self.base().get_tree().unwrap_or_else(|| panic!("Node '{}' has no tree", $self.base().get_name())).change_scene_to_packed(&$packed_scene)
+    };
+}
+
+/// Quits the game, optionally with an exit code. Per Godot semantics the quit is scheduled and happens at the end of the current frame.
+/// 
+/// # Panics
+/// 
+/// Panics if the current node has no tree.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Quit from the main menu
+/// quit!(self);
+/// // Quit with an error exit code
+/// quit!(self, 1);
+/// 
+/// // Expanded
+/// self.base().get_tree().unwrap_or_else(|| panic!("Node '{}' has no tree", self.base().get_name())).quit();
+/// self.base().get_tree().unwrap_or_else(|| panic!("Node '{}' has no tree", self.base().get_name())).quit_ex().exit_code(1).done();
+/// ```
+#[macro_export]
+macro_rules! quit {
+    ($self:ident) => {
+        $self.base().get_tree().unwrap_or_else(|| panic!("Node '{}' has no tree", $self.base().get_name())).quit()
+    };
+    ($self:ident, $exit_code:expr) => {
+        $self.base().get_tree().unwrap_or_else(|| panic!("Node '{}' has no tree", $self.base().get_name())).quit_ex().exit_code($exit_code).done()
+    };
+}
+
+/// Pauses the tree of the current node.
+/// 
+/// # Panics
+/// 
+/// Panics if the current node has no tree.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Pause the game when the pause menu opens
+/// pause!(self);
+/// 
+/// // Expanded
+/// self.base().get_tree().unwrap_or_else(|| panic!("Node '{}' has no tree", self.base().get_name())).set_pause(true);
+/// ```
+#[macro_export]
+macro_rules! pause {
+    ($self:ident) => {
+        $self.base().get_tree().unwrap_or_else(|| panic!("Node '{}' has no tree", $self.base().get_name())).set_pause(true)
+    };
+}
+
+/// Unpauses the tree of the current node.
+/// 
+/// # Panics
+/// 
+/// Panics if the current node has no tree.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Resume the game when the pause menu closes
+/// unpause!(self);
+/// 
+/// // Expanded
+/// self.base().get_tree().unwrap_or_else(|| panic!("Node '{}' has no tree", self.base().get_name())).set_pause(false);
+/// ```
+#[macro_export]
+macro_rules! unpause {
+    ($self:ident) => {
+        $self.base().get_tree().unwrap_or_else(|| panic!("Node '{}' has no tree", $self.base().get_name())).set_pause(false)
+    };
+}
+
+/// Pauses the tree of the current node if it is running, or unpauses it if it is paused.
+/// 
+/// # Panics
+/// 
+/// Panics if the current node has no tree.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Toggle the pause menu
+/// toggle_pause!(self);
+/// 
+/// // Expanded
+/// {
+///     let mut tree = self.base().get_tree().unwrap_or_else(|| panic!("Node '{}' has no tree", self.base().get_name()));
+///     let paused = tree.is_paused();
+///     tree.set_pause(!paused);
+/// };
+/// ```
+#[macro_export]
+macro_rules! toggle_pause {
+    ($self:ident) => {{
+        let mut tree = $self.base().get_tree().unwrap_or_else(|| panic!("Node '{}' has no tree", $self.base().get_name()));
+        let paused = tree.is_paused();
+        tree.set_pause(!paused);
+    }};
+}
+
+/// Loads a PackedScene from its path, instantiates it, and adds the instance as a child of self. Can choose to specify
+/// the scene's root node type, otherwise defaults to Node. Returns the instance for further configuration.
+/// 
+/// # Panics
+/// 
+/// Panics if the scene cannot be loaded or its root node is not of the given type.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Spawn a mob and move it to the spawn point
+/// let mut mob: Gd<Mob> = spawn!(self, Mob, "res://mob.tscn");
+/// mob.set_position(spawn_point);
+/// 
+/// // Expanded
+/// let mut mob: Gd<Mob> = {
+///     let instance = instantiate!(Mob, "res://mob.tscn");
+///     self.base_mut().add_child(&instance);
+///     instance
+/// };
+/// mob.set_position(spawn_point);
+/// ```
+#[macro_export]
+macro_rules! spawn {
+    ($self:ident, $path:expr) => {
+        spawn!($self, Node, $path)
+    };
+    ($self:ident, $node_type:ty, $path:expr) => {{
+        let instance = instantiate!($node_type, $path);
+        $self.base_mut().add_child(&instance);
+        instance
+    }};
+}
+
+/// Returns the parent of self as a Node.
+/// 
+/// # Panics
+/// 
+/// Panics if self has no parent.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Get the node containing self
+/// let container: Gd<Node> = parent!(self);
+/// 
+/// // Expanded
+/// let container: Gd<Node> = self.base().get_parent().unwrap_or_else(|| panic!("Node '{}' has no parent", self.base().get_name()));
+/// ```
+#[macro_export]
+macro_rules! parent {
+    ($self:ident) => {
+        $self.base().get_parent().unwrap_or_else(|| panic!("Node '{}' has no parent", $self.base().get_name()))
+    };
+}
+
+/// Returns the parent of self cast to the given type.
+/// 
+/// # Panics
+/// 
+/// Panics if self has no parent, or the parent is not of the given type.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Get the level self was spawned into
+/// let level: Gd<Level> = parent_as!(self, Level);
+/// 
+/// // Expanded
+/// let level: Gd<Level> = self.base().get_parent().unwrap_or_else(|| panic!("Node '{}' has no parent", self.base().get_name())).cast::<Level>();
+/// ```
+#[macro_export]
+macro_rules! parent_as {
+    ($self:ident, $node_type:ty) => {
+        $self.base().get_parent().unwrap_or_else(|| panic!("Node '{}' has no parent", $self.base().get_name())).cast::<$node_type>()
+    };
+}
+
+/// Returns the parent of self without panicking. Can choose to specify the parent's type, otherwise defaults to Node.
+/// 
+/// Returns None if self has no parent, or the parent is not of the given type.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// let container: Option<Gd<Node>> = try_parent!(self); // None for root and freshly created nodes
+/// let level: Option<Gd<Level>> = try_parent!(self, Level); // Also None if the parent is not a Level
+/// 
+/// // Expanded
+/// let container: Option<Gd<Node>> = self.base().get_parent();
+/// let level: Option<Gd<Level>> = self.base().get_parent().and_then(|parent| parent.try_cast::<Level>().ok());
+/// ```
+#[macro_export]
+macro_rules! try_parent {
+    ($self:ident) => {
+        $self.base().get_parent()
+    };
+    ($self:ident, $node_type:ty) => {
+        $self.base().get_parent().and_then(|parent| parent.try_cast::<$node_type>().ok())
+    };
+}
+
+/// Sets a property on the provided node by name, converting the value to a Variant. Useful for nodes whose properties are not exposed to Rust, such as GDScript nodes.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Speed up a GDScript enemy
+/// set!(enemy, "speed", 5.0);
+/// 
+/// // Expanded
+/// enemy.set("speed", &5.0.to_variant());
+/// ```
+#[macro_export]
+macro_rules! set {
+    ($node:expr, $property:expr, $value:expr) => {
+        $node.set($property, &$value.to_variant())
+    };
+}
+
+/// Gets a property on the provided node by name, as a Variant. Useful for nodes whose properties are not exposed to Rust, such as GDScript nodes.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Read a GDScript enemy's speed
+/// let speed: f64 = get!(enemy, "speed").to::<f64>();
+/// 
+/// // Expanded
+/// let speed: f64 = enemy.get("speed").to::<f64>();
+/// ```
+#[macro_export]
+macro_rules! get {
+    ($node:expr, $property:expr) => {
+        $node.get($property)
+    };
+}
+
+/// Calls a method on the provided node by name with any number of arguments, each converted to a Variant. Returns the result as a Variant.
+/// 
+/// # Panics
+/// 
+/// Panics if the method does not exist on the node.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Make a GDScript enemy take damage
+/// call!(enemy, "take_damage", 10);
+/// 
+/// // Ask a GDScript enemy if it's still alive
+/// let alive: bool = call!(enemy, "is_alive").to::<bool>();
+/// 
+/// // Expanded
+/// enemy.call("take_damage", &[10.to_variant()]);
+/// let alive: bool = enemy.call("is_alive", &[]).to::<bool>();
+/// ```
+#[macro_export]
+macro_rules! call {
+    ($node:expr, $method:expr) => {
+        $node.call($method, &[])
+    };
+    ($node:expr, $method:expr, $($arg:expr),+ $(,)?) => {
+        $node.call($method, &[$($arg.to_variant()),+])
+    };
+}
+
+/// Returns whether the provided node has a method with the provided name.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Only damage GDScript nodes that know how to take damage
+/// if has_method!(body, "take_damage") {
+///     call!(body, "take_damage", 10);
+/// }
+/// 
+/// // Expanded
+/// if body.has_method("take_damage") {
+///     body.call("take_damage", &[10.to_variant()]);
+/// }
+/// ```
+#[macro_export]
+macro_rules! has_method {
+    ($node:expr, $method:expr) => {
+        $node.has_method($method)
+    };
+}
+
+/// Calls a method on the provided node by name at idle time, with any number of arguments, each converted to a Variant.
+/// This is the safe way to mutate the tree (e.g. adding children or freeing nodes) from physics callbacks and signals.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Reset the level once the current physics step is done
+/// call_deferred!(level, "reset");
+/// 
+/// // Add a pickup to the level once the current physics step is done
+/// call_deferred!(level, "add_child", pickup);
+/// 
+/// // Expanded
+/// level.call_deferred("reset", &[]);
+/// level.call_deferred("add_child", &[pickup.to_variant()]);
+/// ```
+#[macro_export]
+macro_rules! call_deferred {
+    ($node:expr, $method:expr) => {
+        $node.call_deferred($method, &[])
+    };
+    ($node:expr, $method:expr, $($arg:expr),+ $(,)?) => {
+        $node.call_deferred($method, &[$($arg.to_variant()),+])
+    };
+}
+
+/// Calls a method by name on the provided node on all connected peers, with any number of arguments, each converted to a Variant.
+/// 
+/// Note: Method must be registered as an RPC, either in GDScript with @rpc or with rpc_config.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Tell every peer where the player is
+/// rpc!(player, "sync_position", position);
+/// 
+/// // Expanded
+/// player.rpc("sync_position", &[position.to_variant()]);
+/// ```
+#[macro_export]
+macro_rules! rpc {
+    ($node:expr, $method:expr) => {
+        $node.rpc($method, &[])
+    };
+    ($node:expr, $method:expr, $($arg:expr),+ $(,)?) => {
+        $node.rpc($method, &[$($arg.to_variant()),+])
+    };
+}
+
+/// Calls a method by name on the provided node on a specific peer, with any number of arguments, each converted to a Variant.
+/// 
+/// Note: Method must be registered as an RPC, either in GDScript with @rpc or with rpc_config.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Tell the server where the player is
+/// rpc_id!(player, 1, "sync_position", position);
+/// 
+/// // Expanded
+/// player.rpc_id(1, "sync_position", &[position.to_variant()]);
+/// ```
+#[macro_export]
+macro_rules! rpc_id {
+    ($node:expr, $peer_id:expr, $method:expr) => {
+        $node.rpc_id($peer_id, $method, &[])
+    };
+    ($node:expr, $peer_id:expr, $method:expr, $($arg:expr),+ $(,)?) => {
+        $node.rpc_id($peer_id, $method, &[$($arg.to_variant()),+])
+    };
+}
+
+/// Returns a Gd pointer to self, for passing self to APIs that expect a Gd\<Self\>.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Register self with the game's enemy manager
+/// manager.bind_mut().register(self_gd!(self));
+/// 
+/// // Expanded
+/// manager.bind_mut().register(self.to_gd());
+/// ```
+#[macro_export]
+macro_rules! self_gd {
+    ($self:ident) => {
+        $self.to_gd()
+    };
+}
+
+/// Moves self to a new parent. Can choose whether to keep the global transform, otherwise it is kept.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Pick up the item, keeping it where it is in the world
+/// reparent!(self, hand);
+/// // Pick up the item, snapping it into the hand
+/// reparent!(self, hand, keep_transform: false);
+/// 
+/// // Expanded, the arguments are evaluated first so they can borrow self
+/// {
+///     let new_parent = hand;
+///     self.base_mut().reparent(&new_parent)
+/// };
+/// {
+///     let new_parent = hand;
+///     let keep_transform = false;
+///     self.base_mut().reparent_ex(&new_parent).keep_global_transform(keep_transform).done()
+/// };
+/// ```
+#[macro_export]
+macro_rules! reparent {
+    ($self:ident, $new_parent:expr) => {{
+        let new_parent = $new_parent;
+        $self.base_mut().reparent(&new_parent)
+    }};
+    ($self:ident, $new_parent:expr, keep_transform: $keep_transform:expr) => {{
+        let new_parent = $new_parent;
+        let keep_transform = $keep_transform;
+        $self.base_mut().reparent_ex(&new_parent).keep_global_transform(keep_transform).done()
+    }};
+}
+
+/// Adds the provided node as a child of self. Can choose to force a human-readable name for the child, and whether
+/// and where to add it as an internal child, otherwise neither is done.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Add a bullet to self
+/// add_child!(self, bullet);
+/// // Same but giving the bullet a readable name like "Bullet2" instead of "@Bullet@2"
+/// add_child!(self, bullet, readable: true);
+/// // Add a helper node that's hidden from get_children
+/// add_child!(self, helper, internal: InternalMode::BACK);
+/// // Both together, with a child cloned from a field of self
+/// add_child!(self, self.helper.clone(), readable: true, internal: InternalMode::BACK);
+/// 
+/// // Expanded, the arguments are evaluated first so they can borrow self
+/// {
+///     let child = bullet;
+///     self.base_mut().add_child(&child)
+/// };
+/// {
+///     let child = bullet;
+///     let readable = true;
+///     self.base_mut().add_child_ex(&child).force_readable_name(readable).done()
+/// };
+/// {
+///     let child = helper;
+///     let internal = InternalMode::BACK;
+///     self.base_mut().add_child_ex(&child).internal(internal).done()
+/// };
+/// {
+///     let child = self.helper.clone();
+///     let readable = true;
+///     let internal = InternalMode::BACK;
+///     self.base_mut().add_child_ex(&child).force_readable_name(readable).internal(internal).done()
+/// };
+/// ```
+#[macro_export]
+macro_rules! add_child {
+    ($self:ident, $child:expr) => {{
+        let child = $child;
+        $self.base_mut().add_child(&child)
+    }};
+    ($self:ident, $child:expr, readable: $readable:expr) => {{
+        let child = $child;
+        let readable = $readable;
+        $self.base_mut().add_child_ex(&child).force_readable_name(readable).done()
+    }};
+    ($self:ident, $child:expr, internal: $internal:expr) => {{
+        let child = $child;
+        let internal = $internal;
+        $self.base_mut().add_child_ex(&child).internal(internal).done()
+    }};
+    ($self:ident, $child:expr, readable: $readable:expr, internal: $internal:expr) => {{
+        let child = $child;
+        let readable = $readable;
+        let internal = $internal;
+        $self.base_mut().add_child_ex(&child).force_readable_name(readable).internal(internal).done()
+    }};
+}
+
+/// Removes the provided child from self without freeing it, so it can be added back later.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Return a bullet to the pool, then reuse it for the next shot
+/// remove_child!(self, bullet.clone());
+/// add_child!(self, bullet);
+/// 
+/// // Expanded, the child is evaluated first so it can borrow self
+/// {
+///     let child = bullet.clone();
+///     self.base_mut().remove_child(&child)
+/// };
+/// {
+///     let child = bullet;
+///     self.base_mut().add_child(&child)
+/// };
+/// ```
+#[macro_export]
+macro_rules! remove_child {
+    ($self:ident, $child:expr) => {{
+        let child = $child;
+        $self.base_mut().remove_child(&child)
+    }};
+}
+
+/// Returns the child of self at the provided index. Can choose to specify node type, otherwise defaults to Node.
+/// Negative indices count from the last child.
+/// 
+/// # Panics
+/// 
+/// Panics if there is no child at the index, or the child is not of the given type.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// let first_tab: Gd<Node> = child!(self, 0); // First child of self
+/// let first_tab: Gd<Tab> = child!(self, Tab, 0); // Same but cast to Tab
+/// 
+/// // Expanded
+/// let first_tab: Gd<Node> = {
+///     let index = 0;
+///     self.base().get_child(index).unwrap_or_else(|| panic!("Node '{}' has no child at index {}", self.base().get_name(), index))
+/// };
+/// let first_tab: Gd<Tab> = child!(self, 0).cast::<Tab>();
+/// ```
+#[macro_export]
+macro_rules! child {
+    ($self:ident, $index:expr) => {{
+        let index = $index;
+        $self.base().get_child(index).unwrap_or_else(|| panic!("Node '{}' has no child at index {}", $self.base().get_name(), index))
+    }};
+    ($self:ident, $node_type:ty, $index:expr) => {
+        child!($self, $index).cast::<$node_type>()
+    };
+}
+
+/// Returns the number of children of self.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Get the index of the last tab
+/// let last_tab: i32 = child_count!(self) - 1;
+/// 
+/// // Expanded
+/// let last_tab: i32 = self.base().get_child_count() - 1;
+/// ```
+#[macro_export]
+macro_rules! child_count {
+    ($self:ident) => {
+        $self.base().get_child_count()
+    };
+}
+
+/// Returns the children of self. Can choose to specify node type, in which case children that are not of the given
+/// type are skipped and the rest are returned as a Vec.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// let children: Array<Gd<Node>> = children!(self); // All children of self
+/// let mobs: Vec<Gd<Mob>> = children!(self, Mob); // Only the children of self that are Mobs
+/// 
+/// // Slow down every mob
+/// for mut mob in children!(self, Mob) {
+///     mob.bind_mut().slow_down();
+/// }
+/// 
+/// // Expanded
+/// let children: Array<Gd<Node>> = self.base().get_children();
+/// let mobs: Vec<Gd<Mob>> = self.base().get_children()
+///     .iter_shared()
+///     .filter_map(|child| child.try_cast::<Mob>().ok())
+///     .collect::<Vec<Gd<Mob>>>();
+/// ```
+#[macro_export]
+macro_rules! children {
+    ($self:ident) => {
+        $self.base().get_children()
+    };
+    ($self:ident, $node_type:ty) => {
+        $self.base().get_children()
+            .iter_shared()
+            .filter_map(|child| child.try_cast::<$node_type>().ok())
+            .collect::<Vec<Gd<$node_type>>>()
+    };
+}
+
+/// Returns the distance between the global positions of self and the provided node.
+/// 
+/// Note: Self and the other node must either both be Node2D or both be Node3D.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Chase the player once they get close enough
+/// let player: Gd<Node2D> = n!(self, Node2D, "../Player");
+/// if dist!(self, player) < AGGRO_RANGE {
+///     self.chase();
+/// }
+/// 
+/// // Expanded
+/// if self.base().get_global_position().distance_to(player.get_global_position()) < AGGRO_RANGE {
+///     self.chase();
+/// }
+/// ```
+#[macro_export]
+macro_rules! dist {
+    ($self:ident, $other:expr) => {
+        $self.base().get_global_position().distance_to($other.get_global_position())
+    };
+}
+
+/// Rotates self to face the provided global position.
+/// 
+/// Note: Self must be a Node2D, in which case the position is a Vector2, or a Node3D, in which case it is a Vector3.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Aim the turret at the player
+/// look_at!(self, n!(self, Node2D, "../Player").get_global_position());
+/// 
+/// // Expanded, the target is evaluated first so it can borrow self
+/// {
+///     let target = self.base().get_node_as::<Node2D>("../Player").get_global_position();
+///     self.base_mut().look_at(target)
+/// };
+/// ```
+#[macro_export]
+macro_rules! look_at {
+    ($self:ident, $target:expr) => {{
+        let target = $target;
+        $self.base_mut().look_at(target)
+    }};
+}
+
+/// Returns the global position of self, or sets it if a new position is provided.
+/// 
+/// Note: Self must be a Node2D, in which case the position is a Vector2, or a Node3D, in which case it is a Vector3.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Read where self is in the world
+/// let position: Vector2 = global_pos!(self);
+/// // Move self up by 10 pixels
+/// global_pos!(self, global_pos!(self) + Vector2::UP * 10.0);
+/// 
+/// // Expanded
+/// let position: Vector2 = self.base().get_global_position();
+/// {
+///     let new_position = self.base().get_global_position() + Vector2::UP * 10.0;
+///     self.base_mut().set_global_position(new_position)
+/// };
+/// ```
+#[macro_export]
+macro_rules! global_pos {
+    ($self:ident) => {
+        $self.base().get_global_position()
+    };
+    ($self:ident, $new_position:expr) => {{
+        let new_position = $new_position;
+        $self.base_mut().set_global_position(new_position)
+    }};
+}
+
+/// Rotates self by the provided angle in radians.
+/// 
+/// Note: Self must be a Node2D.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Spin the coin a little every frame
+/// rotate!(self, self.spin_speed * delta as f32);
+/// 
+/// // Expanded, the angle is evaluated first so it can borrow self
+/// {
+///     let radians = self.spin_speed * delta as f32;
+///     self.base_mut().rotate(radians)
+/// };
+/// ```
+#[macro_export]
+macro_rules! rotate {
+    ($self:ident, $radians:expr) => {{
+        let radians = $radians;
+        $self.base_mut().rotate(radians)
+    }};
+}
+
+/// Rotates self by the provided angle in degrees, converting it to radians first.
+/// 
+/// Note: Self must be a Node2D.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Turn the turret 30 degrees clockwise
+/// rotate_deg!(self, 30.0);
+/// 
+/// // Expanded
+/// rotate!(self, real::to_radians(30.0));
+/// ```
+#[macro_export]
+macro_rules! rotate_deg {
+    ($self:ident, $degrees:expr) => {
+        rotate!($self, real::to_radians($degrees))
+    };
+}
+
+/// Sets the velocity of self and then moves it with move_and_slide, returning whether it collided.
+/// 
+/// Note: Self must be a CharacterBody2D, in which case the velocity is a Vector2, or a CharacterBody3D, in which case it is a Vector3.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Move in the direction being pressed
+/// let direction: Vector2 = act_axis_vec!("move_left", "move_right", "move_up", "move_down");
+/// move_and_slide!(self, direction * SPEED);
+/// 
+/// // Expanded
+/// let direction: Vector2 = act_axis_vec!("move_left", "move_right", "move_up", "move_down");
+/// {
+///     let velocity = direction * SPEED;
+///     self.base_mut().set_velocity(velocity);
+///     self.base_mut().move_and_slide()
+/// };
+/// ```
+#[macro_export]
+macro_rules! move_and_slide {
+    ($self:ident, $velocity:expr) => {{
+        let velocity = $velocity;
+        $self.base_mut().set_velocity(velocity);
+        $self.base_mut().move_and_slide()
+    }};
+}
+
+/// Returns whether self was touching the floor during the last call to move_and_slide.
+/// 
+/// Note: Self must be a CharacterBody2D or CharacterBody3D.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Only let the player jump when standing on something
+/// if on_floor!(self) && act_press_down!("jump") {
+///     self.jump();
+/// }
+/// 
+/// // Expanded
+/// if self.base().is_on_floor() && act_press_down!("jump") {
+///     self.jump();
+/// }
+/// ```
+#[macro_export]
+macro_rules! on_floor {
+    ($self:ident) => {
+        $self.base().is_on_floor()
+    };
+}
+
+/// Returns whether self was touching a wall during the last call to move_and_slide.
+/// 
+/// Note: Self must be a CharacterBody2D or CharacterBody3D.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Let the player wall jump
+/// if on_wall!(self) && act_press_down!("jump") {
+///     self.wall_jump();
+/// }
+/// 
+/// // Expanded
+/// if self.base().is_on_wall() && act_press_down!("jump") {
+///     self.wall_jump();
+/// }
+/// ```
+#[macro_export]
+macro_rules! on_wall {
+    ($self:ident) => {
+        $self.base().is_on_wall()
+    };
+}
+
+/// Returns whether self was touching the ceiling during the last call to move_and_slide.
+/// 
+/// Note: Self must be a CharacterBody2D or CharacterBody3D.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Cut the jump short when the player bumps their head
+/// if on_ceiling!(self) {
+///     self.velocity.y = 0.0;
+/// }
+/// 
+/// // Expanded
+/// if self.base().is_on_ceiling() {
+///     self.velocity.y = 0.0;
+/// }
+/// ```
+#[macro_export]
+macro_rules! on_ceiling {
+    ($self:ident) => {
+        $self.base().is_on_ceiling()
+    };
+}
+
+/// Returns the time in seconds since the previous idle frame, for reading delta outside of process.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Regenerate health from a helper method called by process
+/// self.health += REGEN_RATE * delta!(self);
+/// 
+/// // Expanded
+/// self.health += REGEN_RATE * self.base().get_process_delta_time();
+/// ```
+#[macro_export]
+macro_rules! delta {
+    ($self:ident) => {
+        $self.base().get_process_delta_time()
+    };
+}
+
+/// Returns the time in seconds since the previous physics frame, for reading delta outside of physics_process.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Apply gravity from a helper method called by physics_process
+/// self.velocity.y += GRAVITY * phys_delta!(self) as f32;
+/// 
+/// // Expanded
+/// self.velocity.y += GRAVITY * self.base().get_physics_process_delta_time() as f32;
+/// ```
+#[macro_export]
+macro_rules! phys_delta {
+    ($self:ident) => {
+        $self.base().get_physics_process_delta_time()
+    };
+}
+
+/// Returns the time since the engine started in milliseconds, or in microseconds if usec is passed.
+/// 
+/// Note: This time is monotonic, so it is never affected by changes to the system clock and is safe for cooldowns and profiling.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Only allow shooting every 250 milliseconds
+/// if time_now!() - self.last_shot >= 250 {
+///     self.last_shot = time_now!();
+///     self.shoot();
+/// }
+/// 
+/// // Time how long pathfinding takes
+/// let start: u64 = time_now!(usec);
+/// self.find_path();
+/// let elapsed: u64 = time_now!(usec) - start;
+/// 
+/// // Expanded
+/// let now: u64 = Time::singleton().get_ticks_msec();
+/// let start: u64 = Time::singleton().get_ticks_usec();
+/// ```
+#[macro_export]
+macro_rules! time_now {
+    () => {
+        Time::singleton().get_ticks_msec()
+    };
+    (usec) => {
+        Time::singleton().get_ticks_usec()
+    };
+}
+
+/// Returns a random float between the two provided bounds, inclusive, using Godot's global random number generator.
+/// Passing int as a third argument returns a random integer instead.
+/// 
+/// Note: As this uses Godot's generator, it respects seeds set with godot::global::seed.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Pick a random spawn delay
+/// let delay: f64 = rand_range!(0.5, 1.5);
+/// // Roll a die
+/// let roll: i64 = rand_range!(1, 6, int);
+/// 
+/// // Expanded
+/// let delay: f64 = godot::global::randf_range(0.5, 1.5);
+/// let roll: i64 = godot::global::randi_range(1, 6);
+/// ```
+#[macro_export]
+macro_rules! rand_range {
+    ($from:expr, $to:expr) => {
+        godot::global::randf_range($from, $to)
+    };
+    ($from:expr, $to:expr, int) => {
+        godot::global::randi_range($from, $to)
+    };
+}
+
+/// Plays the AudioStreamPlayer child at the provided path. Can choose a position in seconds to play from, otherwise plays from the start.
+/// 
+/// # Panics
+/// 
+/// Panics if node_path cannot be found or is not an AudioStreamPlayer.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Play the hit sound effect
+/// play_sound!(self, "HitSound");
+/// // Resume the music where it was paused
+/// play_sound!(self, "Music", self.music_position);
+/// 
+/// // Expanded
+/// n!(self, AudioStreamPlayer, "HitSound").play();
+/// n!(self, AudioStreamPlayer, "Music").play_ex().from_position(self.music_position).done();
+/// ```
+#[macro_export]
+macro_rules! play_sound {
+    ($self:ident, $node_path:expr) => {
+        n!($self, AudioStreamPlayer, $node_path).play()
+    };
+    ($self:ident, $node_path:expr, $from_position:expr) => {
+        n!($self, AudioStreamPlayer, $node_path).play_ex().from_position($from_position).done()
+    };
+}
+
+/// Plays the provided animation on the AnimationPlayer child at the provided path. Can choose a custom speed and
+/// blend time, otherwise uses the AnimationPlayer's defaults.
+/// 
+/// # Panics
+/// 
+/// Panics if node_path cannot be found or is not an AnimationPlayer.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Start walking
+/// play_anim!(self, "AnimationPlayer", "walk");
+/// // Start running, which is walking but faster
+/// play_anim!(self, "AnimationPlayer", "walk", speed: 2.0);
+/// // Smoothly go back to idling
+/// play_anim!(self, "AnimationPlayer", "idle", blend: 0.2);
+/// // Both together
+/// play_anim!(self, "AnimationPlayer", "idle", speed: 0.5, blend: 0.2);
+/// 
+/// // Expanded
+/// n!(self, AnimationPlayer, "AnimationPlayer").play_ex().name("walk").done();
+/// n!(self, AnimationPlayer, "AnimationPlayer").play_ex().name("walk").custom_speed(2.0).done();
+/// n!(self, AnimationPlayer, "AnimationPlayer").play_ex().name("idle").custom_blend(0.2).done();
+/// n!(self, AnimationPlayer, "AnimationPlayer").play_ex().name("idle").custom_speed(0.5).custom_blend(0.2).done();
+/// ```
+#[macro_export]
+macro_rules! play_anim {
+    ($self:ident, $node_path:expr, $animation:expr) => {
+        n!($self, AnimationPlayer, $node_path).play_ex().name($animation).done()
+    };
+    ($self:ident, $node_path:expr, $animation:expr, speed: $speed:expr) => {
+        n!($self, AnimationPlayer, $node_path).play_ex().name($animation).custom_speed($speed).done()
+    };
+    ($self:ident, $node_path:expr, $animation:expr, blend: $blend:expr) => {
+        n!($self, AnimationPlayer, $node_path).play_ex().name($animation).custom_blend($blend).done()
+    };
+    ($self:ident, $node_path:expr, $animation:expr, speed: $speed:expr, blend: $blend:expr) => {
+        n!($self, AnimationPlayer, $node_path).play_ex().name($animation).custom_speed($speed).custom_blend($blend).done()
+    };
+}
+
+/// Sets the text of the Label child at the provided path. Can choose to specify the node type for other text nodes such as RichTextLabel, otherwise defaults to Label.
+/// 
+/// # Panics
+/// 
+/// Panics if node_path cannot be found or is not of the given type.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Update the score on the HUD
+/// set_text!(self, "ScoreLabel", format!("Score: {}", self.score));
+/// // Update the rich text combat log
+/// set_text!(self, RichTextLabel, "CombatLog", self.log.join("\n"));
+/// 
+/// // Expanded
+/// n!(self, Label, "ScoreLabel").set_text(&format!("Score: {}", self.score).to_string());
+/// n!(self, RichTextLabel, "CombatLog").set_text(&self.log.join("\n").to_string());
+/// ```
+#[macro_export]
+macro_rules! set_text {
+    ($self:ident, $node_path:expr, $text:expr) => {
+        n!($self, Label, $node_path).set_text(&$text.to_string())
+    };
+    ($self:ident, $node_type:ty, $node_path:expr, $text:expr) => {
+        n!($self, $node_type, $node_path).set_text(&$text.to_string())
+    };
+}
+
+/// Shows or hides self, or the child at the provided path. Can choose to specify the child's node type, for example
+/// Node3D, otherwise defaults to CanvasItem.
+/// 
+/// # Panics
+/// 
+/// Panics if node_path cannot be found or is not of the given type.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Show self
+/// set_visible!(self, true);
+/// // Hide the pause menu
+/// set_visible!(self, "PauseMenu", false);
+/// // Hide the 3D crosshair
+/// set_visible!(self, Node3D, "Crosshair", false);
+/// 
+/// // Expanded
+/// {
+///     let visible = true;
+///     self.base_mut().set_visible(visible)
+/// };
+/// n!(self, CanvasItem, "PauseMenu").set_visible(false);
+/// n!(self, Node3D, "Crosshair").set_visible(false);
+/// ```
+#[macro_export]
+macro_rules! set_visible {
+    ($self:ident, $visible:expr) => {{
+        let visible = $visible;
+        $self.base_mut().set_visible(visible)
+    }};
+    ($self:ident, $node_path:expr, $visible:expr) => {
+        n!($self, CanvasItem, $node_path).set_visible($visible)
+    };
+    ($self:ident, $node_type:ty, $node_path:expr, $visible:expr) => {
+        n!($self, $node_type, $node_path).set_visible($visible)
+    };
+}
+
+/// Shows self if it is hidden, or hides it if it is shown.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Toggle the inventory
+/// toggle_visible!(self);
+/// 
+/// // Expanded
+/// {
+///     let visible = self.base().is_visible();
+///     self.base_mut().set_visible(!visible);
+/// };
+/// ```
+#[macro_export]
+macro_rules! toggle_visible {
+    ($self:ident) => {{
+        let visible = $self.base().is_visible();
+        $self.base_mut().set_visible(!visible);
+    }};
+}
+
+/// Tints self, or the CanvasItem child at the provided path, with the provided color.
+/// 
+/// # Panics
+/// 
+/// Panics if node_path cannot be found or is not a CanvasItem.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Flash red when hit
+/// modulate!(self, Color::from_rgb(1.0, 0.0, 0.0));
+/// // Dim the sprite while invulnerable
+/// modulate!(self, "Sprite", Color::from_rgba(1.0, 1.0, 1.0, 0.5));
+/// 
+/// // Expanded
+/// {
+///     let color = Color::from_rgb(1.0, 0.0, 0.0);
+///     self.base_mut().set_modulate(color)
+/// };
+/// n!(self, CanvasItem, "Sprite").set_modulate(Color::from_rgba(1.0, 1.0, 1.0, 0.5));
+/// ```
+#[macro_export]
+macro_rules! modulate {
+    ($self:ident, $color:expr) => {{
+        let color = $color;
+        $self.base_mut().set_modulate(color)
+    }};
+    ($self:ident, $node_path:expr, $color:expr) => {
+        n!($self, CanvasItem, $node_path).set_modulate($color)
+    };
+}
+
+/// Returns the provided engine singleton, such as Os, Engine, Time, or DisplayServer.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Check which platform the game is running on
+/// let platform: GString = singleton!(Os).get_name();
+/// // Slow the game down for a bullet time effect
+/// singleton!(Engine).set_time_scale(0.5);
+/// 
+/// // Expanded
+/// let platform: GString = Os::singleton().get_name();
+/// Engine::singleton().set_time_scale(0.5);
+/// ```
+#[macro_export]
+macro_rules! singleton {
+    ($singleton_type:ty) => {
+        <$singleton_type>::singleton()
+    };
+}
+
+/// Returns the command-line arguments the game was launched with, or only the user arguments after "--" if user is passed.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Check whether the editor-style arguments were passed
+/// let args: PackedStringArray = cmdline!();
+/// // Start as a dedicated server when launched with "-- --server"
+/// let headless: bool = cmdline!(user).contains("--server");
+/// 
+/// // Expanded
+/// let args: PackedStringArray = Os::singleton().get_cmdline_args();
+/// let headless: bool = Os::singleton().get_cmdline_user_args().contains("--server");
+/// ```
+#[macro_export]
+macro_rules! cmdline {
+    () => {
+        Os::singleton().get_cmdline_args()
+    };
+    (user) => {
+        Os::singleton().get_cmdline_user_args()
+    };
+}
+
+/// Returns the value of the provided project setting as a Variant. Can choose a default to return, converted to a
+/// Variant, if the setting does not exist, otherwise returns a nil Variant.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Show the game's name in the title screen
+/// let name: GString = setting!("application/config/name").to::<GString>();
+/// // Read the custom difficulty setting, falling back to normal
+/// let difficulty: i64 = setting!("game/difficulty", 1).to::<i64>();
+/// 
+/// // Expanded
+/// let name: GString = ProjectSettings::singleton().get_setting("application/config/name").to::<GString>();
+/// let difficulty: i64 = ProjectSettings::singleton().get_setting_ex("game/difficulty").default_value(&1.to_variant()).done().to::<i64>();
+/// ```
+#[macro_export]
+macro_rules! setting {
+    ($path:expr) => {
+        ProjectSettings::singleton().get_setting($path)
+    };
+    ($path:expr, $default:expr) => {
+        ProjectSettings::singleton().get_setting_ex($path).default_value(&$default.to_variant()).done()
+    };
+}
+
+/// Saves the provided data to the file at the provided path, converting it to a Variant. Returns the Error from
+/// opening the file if it cannot be opened for writing, or from writing to it if the data could not be stored.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Save the player's progress
+/// let mut save = VarDictionary::new();
+/// save.set("level", 3);
+/// save_var!("user://save.dat", save)?;
+/// 
+/// // Expanded
+/// match FileAccess::open("user://save.dat", godot::classes::file_access::ModeFlags::WRITE) {
+///     Some(mut file) => {
+///         file.store_var(&save.to_variant());
+///         let error = file.get_error();
+///         file.close();
+///         match error {
+///             Error::OK => Ok(()),
+///             error => Err(error),
+///         }
+///     }
+///     None => Err(FileAccess::get_open_error()),
+/// }?;
+/// ```
+/// 
+/// ```
+/// # use godot_macros::*;
+/// # use std::{cell::RefCell, collections::HashMap};
+/// # #[allow(non_camel_case_types)]
+/// # #[derive(Clone, Debug, PartialEq)] enum Error { OK, ERR_FILE_NOT_FOUND, ERR_FILE_CANT_WRITE }
+/// # #[derive(Clone, Debug, Default, PartialEq)] struct Variant(Vec<(String, i64)>);
+/// # impl Variant { fn to<T: From<Variant>>(&self) -> T { T::from(self.clone()) } }
+/// # #[derive(Clone, Debug, Default, PartialEq)] struct VarDictionary(Vec<(String, i64)>);
+/// # impl VarDictionary {
+/// #     fn new() -> Self { Self::default() }
+/// #     fn set(&mut self, key: &str, value: i64) { self.0.push((key.to_string(), value)) }
+/// #     fn to_variant(&self) -> Variant { Variant(self.0.clone()) }
+/// # }
+/// # impl From<Variant> for VarDictionary { fn from(variant: Variant) -> Self { Self(variant.0) } }
+/// # mod godot { pub mod classes { pub mod file_access { pub enum ModeFlags { READ, WRITE } } } }
+/// # use godot::classes::file_access::ModeFlags;
+/// # thread_local! { static DISK: RefCell<HashMap<String, Variant>> = RefCell::new(HashMap::new()); }
+/// # struct FileAccess { path: String, error: Error }
+/// # impl FileAccess {
+/// #     fn open(path: &str, flags: ModeFlags) -> Option<FileAccess> {
+/// #         let exists = DISK.with(|disk| disk.borrow().contains_key(path));
+/// #         match flags {
+/// #             ModeFlags::READ if !exists => None,
+/// #             _ => Some(FileAccess { path: path.to_string(), error: Error::OK }),
+/// #         }
+/// #     }
+/// #     fn get_open_error() -> Error { Error::ERR_FILE_NOT_FOUND }
+/// #     fn store_var(&mut self, value: &Variant) -> bool {
+/// #         if self.path.starts_with("res://") {
+/// #             self.error = Error::ERR_FILE_CANT_WRITE; // res:// is read-only in exported games
+/// #             return false;
+/// #         }
+/// #         DISK.with(|disk| disk.borrow_mut().insert(self.path.clone(), value.clone()));
+/// #         true
+/// #     }
+/// #     fn get_var(&mut self) -> Variant { DISK.with(|disk| disk.borrow()[&self.path].clone()) }
+/// #     fn get_error(&self) -> Error { self.error.clone() }
+/// #     fn close(&mut self) {}
+/// # }
+/// // A saved dictionary loads back unchanged
+/// let mut save = VarDictionary::new();
+/// save.set("level", 3);
+/// assert_eq!(save_var!("user://save.dat", save), Ok(()));
+/// assert_eq!(load_var!("user://save.dat").unwrap().to::<VarDictionary>(), save);
+/// 
+/// // Failing to open or write to the file is returned as an Err
+/// assert_eq!(load_var!("user://missing.dat"), Err(Error::ERR_FILE_NOT_FOUND));
+/// assert_eq!(save_var!("res://save.dat", save), Err(Error::ERR_FILE_CANT_WRITE));
+/// ```
+#[macro_export]
+macro_rules! save_var {
+    ($path:expr, $data:expr) => {
+        match FileAccess::open($path, godot::classes::file_access::ModeFlags::WRITE) {
+            Some(mut file) => {
+                file.store_var(&$data.to_variant());
+                let error = file.get_error();
+                file.close();
+                match error {
+                    Error::OK => Ok(()),
+                    error => Err(error),
+                }
+            }
+            None => Err(FileAccess::get_open_error()),
+        }
+    };
+}
+
+/// Loads data saved with save_var! from the file at the provided path, as a Variant. Returns the Error from opening
+/// the file if it cannot be opened for reading, such as when no save exists yet.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Load the player's progress
+/// let save: VarDictionary = load_var!("user://save.dat")?.to::<VarDictionary>();
+/// 
+/// // Expanded
+/// let save: VarDictionary = match FileAccess::open("user://save.dat", godot::classes::file_access::ModeFlags::READ) {
+///     Some(mut file) => {
+///         let data = file.get_var();
+///         file.close();
+///         Ok(data)
+///     }
+///     None => Err(FileAccess::get_open_error()),
+/// }?.to::<VarDictionary>();
+/// ```
+#[macro_export]
+macro_rules! load_var {
+    ($path:expr) => {
+        match FileAccess::open($path, godot::classes::file_access::ModeFlags::READ) {
+            Some(mut file) => {
+                let data = file.get_var();
+                file.close();
+                Ok(data)
+            }
+            None => Err(FileAccess::get_open_error()),
+        }
+    };
+}
+
+/// Returns the visible rectangle of the viewport of the current node.
+/// 
+/// # Panics
+/// 
+/// Panics if the current node has no viewport.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Wrap around to the left edge when leaving the right edge of the screen
+/// let screen: Rect2 = viewport_rect!(self);
+/// if global_pos!(self).x > screen.end().x {
+///     global_pos!(self, Vector2::new(screen.position.x, global_pos!(self).y));
+/// }
+/// 
+/// // Expanded
+/// let screen: Rect2 = self.base().get_viewport().unwrap_or_else(|| panic!("Node '{}' has no viewport", self.base().get_name())).get_visible_rect();
+/// ```
+#[macro_export]
+macro_rules! viewport_rect {
+    ($self:ident) => {
+        $self.base().get_viewport().unwrap_or_else(|| panic!("Node '{}' has no viewport", $self.base().get_name())).get_visible_rect()
+    };
+}
+
+/// Returns the size of the visible rectangle of the viewport of the current node.
+/// 
+/// # Panics
+/// 
+/// Panics if the current node has no viewport.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Spawn in the middle of the screen
+/// let center: Vector2 = viewport_size!(self) / 2.0;
+/// 
+/// // Expanded
+/// let center: Vector2 = viewport_rect!(self).size / 2.0;
+/// ```
+#[macro_export]
+macro_rules! viewport_size {
+    ($self:ident) => {
+        viewport_rect!($self).size
+    };
+}
+
+/// Returns the active camera of the viewport of the current node. Returns the Camera2D by default, or the Camera3D if Camera3D is passed.
+/// 
+/// # Panics
+/// 
+/// Panics if the current node has no viewport, or the viewport has no active camera of the given type.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// let camera: Gd<Camera2D> = camera!(self); // Active 2D camera
+/// let camera: Gd<Camera2D> = camera!(self, Camera2D); // Same but explicit
+/// let camera: Gd<Camera3D> = camera!(self, Camera3D); // Active 3D camera
+/// 
+/// // Expanded
+/// let camera: Gd<Camera2D> = self.base().get_viewport().unwrap_or_else(|| panic!("Node '{}' has no viewport", self.base().get_name()))
+///     .get_camera_2d()
+///     .unwrap_or_else(|| panic!("Node '{}' has no active Camera2D", self.base().get_name()));
+/// let camera: Gd<Camera3D> = self.base().get_viewport().unwrap_or_else(|| panic!("Node '{}' has no viewport", self.base().get_name()))
+///     .get_camera_3d()
+///     .unwrap_or_else(|| panic!("Node '{}' has no active Camera3D", self.base().get_name()));
+/// ```
+#[macro_export]
+macro_rules! camera {
+    ($self:ident) => {
+        camera!($self, Camera2D)
+    };
+    ($self:ident, Camera2D) => {
+        $self.base().get_viewport().unwrap_or_else(|| panic!("Node '{}' has no viewport", $self.base().get_name()))
+            .get_camera_2d()
+            .unwrap_or_else(|| panic!("Node '{}' has no active Camera2D", $self.base().get_name()))
+    };
+    ($self:ident, Camera3D) => {
+        $self.base().get_viewport().unwrap_or_else(|| panic!("Node '{}' has no viewport", $self.base().get_name()))
+            .get_camera_3d()
+            .unwrap_or_else(|| panic!("Node '{}' has no active Camera3D", $self.base().get_name()))
+    };
+}
+
+/// Returns the position of the mouse in the 2D world, taking the active camera into account.
+/// 
+/// Note: Self must be a CanvasItem, such as a Node2D.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Aim the turret at the mouse
+/// look_at!(self, world_mouse!(self));
+/// 
+/// // Expanded
+/// look_at!(self, self.base().get_global_mouse_position());
+/// ```
+#[macro_export]
+macro_rules! world_mouse {
+    ($self:ident) => {
+        $self.base().get_global_mouse_position()
+    };
+}
+
+/// Casts a ray in the 2D physics world of self between the two provided global positions, returning the result
+/// Dictionary from intersect_ray, which is empty if nothing was hit. Can choose a collision mask, otherwise the ray
+/// collides with every layer.
+/// 
+/// Note: Self must be a CanvasItem, such as a Node2D. As it queries the physics server directly, it should only be
+/// called from physics_process.
+/// 
+/// # Panics
+/// 
+/// Panics if the current node has no world, or the ray query cannot be created.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Check if anything is in front of the enemy
+/// let ahead: Vector2 = global_pos!(self) + Vector2::RIGHT * 100.0;
+/// let blocked: bool = !raycast!(self, global_pos!(self), ahead).is_empty();
+/// // Check if the enemy can see the player, only colliding with walls on layer 1
+/// let player_position: Vector2 = n!(self, Node2D, "../Player").get_global_position();
+/// let can_see_player: bool = raycast!(self, global_pos!(self), player_position, 1).is_empty();
+/// 
+/// // Expanded
+/// let blocked: bool = !{
+///     let query = PhysicsRayQueryParameters2D::create(global_pos!(self), ahead)
+///         .unwrap_or_else(|| panic!("Node '{}' could not create a ray query", self.base().get_name()));
+///     self.base().get_world_2d().unwrap_or_else(|| panic!("Node '{}' has no world", self.base().get_name()))
+///         .get_direct_space_state()
+///         .unwrap_or_else(|| panic!("Node '{}' has no space state", self.base().get_name()))
+///         .intersect_ray(&query)
+/// }.is_empty();
+/// let can_see_player: bool = {
+///     let query = PhysicsRayQueryParameters2D::create_ex(global_pos!(self), player_position).collision_mask(1).done()
+///         .unwrap_or_else(|| panic!("Node '{}' could not create a ray query", self.base().get_name()));
+///     // Same as above
+/// }.is_empty();
+/// ```
+#[macro_export]
+macro_rules! raycast {
+    ($self:ident, $from:expr, $to:expr) => {{
+        let query = PhysicsRayQueryParameters2D::create($from, $to)
+            .unwrap_or_else(|| panic!("Node '{}' could not create a ray query", $self.base().get_name()));
+        $self.base().get_world_2d().unwrap_or_else(|| panic!("Node '{}' has no world", $self.base().get_name()))
+            .get_direct_space_state()
+            .unwrap_or_else(|| panic!("Node '{}' has no space state", $self.base().get_name()))
+            .intersect_ray(&query)
+    }};
+    ($self:ident, $from:expr, $to:expr, $collision_mask:expr) => {{
+        let query = PhysicsRayQueryParameters2D::create_ex($from, $to).collision_mask($collision_mask).done()
+            .unwrap_or_else(|| panic!("Node '{}' could not create a ray query", $self.base().get_name()));
+        $self.base().get_world_2d().unwrap_or_else(|| panic!("Node '{}' has no world", $self.base().get_name()))
+            .get_direct_space_state()
+            .unwrap_or_else(|| panic!("Node '{}' has no space state", $self.base().get_name()))
+            .intersect_ray(&query)
+    }};
+}
+
+/// Enables or disables the provided collision layer on self. Layers are numbered from 1 to 32, matching the editor.
+/// 
+/// Note: Self must be a CollisionObject2D or CollisionObject3D, such as a body or area.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Move self onto the "enemies" layer, layer 2
+/// set_layer!(self, 2, true);
+/// 
+/// // Expanded
+/// {
+///     let layer_number = 2;
+///     let value = true;
+///     self.base_mut().set_collision_layer_value(layer_number, value)
+/// };
+/// ```
+#[macro_export]
+macro_rules! set_layer {
+    ($self:ident, $layer_number:expr, $value:expr) => {{
+        let layer_number = $layer_number;
+        let value = $value;
+        $self.base_mut().set_collision_layer_value(layer_number, value)
+    }};
+}
+
+/// Enables or disables the provided collision mask layer on self, which controls which layers self detects. Layers are numbered from 1 to 32, matching the editor.
+/// 
+/// Note: Self must be a CollisionObject2D or CollisionObject3D, such as a body or area.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Stop colliding with pickups on layer 3 while dashing
+/// set_mask!(self, 3, false);
+/// 
+/// // Expanded
+/// {
+///     let layer_number = 3;
+///     let value = false;
+///     self.base_mut().set_collision_mask_value(layer_number, value)
+/// };
+/// ```
+#[macro_export]
+macro_rules! set_mask {
+    ($self:ident, $layer_number:expr, $value:expr) => {{
+        let layer_number = $layer_number;
+        let value = $value;
+        $self.base_mut().set_collision_mask_value(layer_number, value)
+    }};
+}
+
+/// Returns the physics bodies currently overlapping self. Can choose to specify body type, in which case bodies that
+/// are not of the given type are skipped and the rest are returned as a Vec.
+/// 
+/// Note: Self must be an Area2D or Area3D.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// let bodies: Array<Gd<Node2D>> = overlapping_bodies!(self); // All bodies in the trigger zone
+/// let players: Vec<Gd<Player>> = overlapping_bodies!(self, Player); // Only the Players in the trigger zone
+/// 
+/// // Expanded
+/// let bodies: Array<Gd<Node2D>> = self.base().get_overlapping_bodies();
+/// let players: Vec<Gd<Player>> = self.base().get_overlapping_bodies()
+///     .iter_shared()
+///     .filter_map(|body| body.try_cast::<Player>().ok())
+///     .collect::<Vec<Gd<Player>>>();
+/// ```
+#[macro_export]
+macro_rules! overlapping_bodies {
+    ($self:ident) => {
+        $self.base().get_overlapping_bodies()
+    };
+    ($self:ident, $body_type:ty) => {
+        $self.base().get_overlapping_bodies()
+            .iter_shared()
+            .filter_map(|body| body.try_cast::<$body_type>().ok())
+            .collect::<Vec<Gd<$body_type>>>()
+    };
+}
+
+/// Returns the areas currently overlapping self. Can choose to specify area type, in which case areas that are not of
+/// the given type are skipped and the rest are returned as a Vec.
+/// 
+/// Note: Self must be an Area2D or Area3D.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// let areas: Array<Gd<Area2D>> = overlapping_areas!(self); // All areas overlapping the pickup
+/// let magnets: Vec<Gd<Magnet>> = overlapping_areas!(self, Magnet); // Only the Magnets overlapping the pickup
+/// 
+/// // Expanded
+/// let areas: Array<Gd<Area2D>> = self.base().get_overlapping_areas();
+/// let magnets: Vec<Gd<Magnet>> = self.base().get_overlapping_areas()
+///     .iter_shared()
+///     .filter_map(|area| area.try_cast::<Magnet>().ok())
+///     .collect::<Vec<Gd<Magnet>>>();
+/// ```
+#[macro_export]
+macro_rules! overlapping_areas {
+    ($self:ident) => {
+        $self.base().get_overlapping_areas()
+    };
+    ($self:ident, $area_type:ty) => {
+        $self.base().get_overlapping_areas()
+            .iter_shared()
+            .filter_map(|area| area.try_cast::<$area_type>().ok())
+            .collect::<Vec<Gd<$area_type>>>()
+    };
+}
+
+/// Gives keyboard and controller focus to self, or to the Control child at the provided path.
+/// 
+/// # Panics
+/// 
+/// Panics if node_path cannot be found or is not a Control.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Focus self when the menu opens so it can be navigated with a controller
+/// grab_focus!(self);
+/// // Focus the start button instead
+/// grab_focus!(self, "StartButton");
+/// 
+/// // Expanded
+/// self.base_mut().grab_focus();
+/// n!(self, Control, "StartButton").grab_focus();
+/// ```
+#[macro_export]
+macro_rules! grab_focus {
+    ($self:ident) => {
+        $self.base_mut().grab_focus()
+    };
+    ($self:ident, $node_path:expr) => {
+        n!($self, Control, $node_path).grab_focus()
+    };
+}
+
+/// Sets a uniform on the ShaderMaterial of self, converting the value to a Variant.
+/// 
+/// Note: Self must be a CanvasItem, such as a Sprite2D.
+/// 
+/// # Panics
+/// 
+/// Panics if self has no material, or its material is not a ShaderMaterial.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Make the hit flash shader half as strong
+/// shader_param!(self, "intensity", 0.5);
+/// 
+/// // Expanded
+/// self.base().get_material()
+///     .and_then(|material| material.try_cast::<ShaderMaterial>().ok())
+///     .unwrap_or_else(|| panic!("Node '{}' has no ShaderMaterial", self.base().get_name()))
+///     .set_shader_parameter("intensity", &0.5.to_variant());
+/// ```
+#[macro_export]
+macro_rules! shader_param {
+    ($self:ident, $param:expr, $value:expr) => {
+        $self.base().get_material()
+            .and_then(|material| material.try_cast::<ShaderMaterial>().ok())
+            .unwrap_or_else(|| panic!("Node '{}' has no ShaderMaterial", $self.base().get_name()))
+            .set_shader_parameter($param, &$value.to_variant())
+    };
+}
+
+/// Creates a NodePath from the provided path.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Make the camera's remote transform follow the player's weapon
+/// n!(self, RemoteTransform2D, "CameraMount").set_remote_node(node_path!("../Player/Weapon"));
+/// 
+/// // Expanded
+/// n!(self, RemoteTransform2D, "CameraMount").set_remote_node(NodePath::from("../Player/Weapon"));
+/// ```
+#[macro_export]
+macro_rules! node_path {
+    ($path:expr) => {
+        NodePath::from($path)
+    };
+}
+
+/// Creates a StringName from the provided string.
+/// 
+/// Note: This does not cache the StringName, so each use creates a new one. For loops that use the same name every
+/// frame, create it once and store it, for example in a field of self.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Create the signal name once for a hot loop
+/// let body_entered: StringName = sname!("body_entered");
+/// 
+/// // Expanded
+/// let body_entered: StringName = StringName::from("body_entered");
 /// ```
 #[macro_export]
-macro_rules! n {
-    ($self:ident, $node_path:expr) => {
-        $self.base().get_node_as::<Node>($node_path)
-    };
-    ($self:ident, $node_type:ty, $node_path:expr) => {
-        $self.base().get_node_as::<$node_type>($node_path)
-    };
-    ($self:ident, $node_path:expr, $node_type:ty) => {
-        $self.base().get_node_as::<$node_type>($node_path)
+macro_rules! sname {
+    ($string:expr) => {
+        StringName::from($string)
     };
 }
 
-/// Macro for quickly getting a mutable reference to a node from its path. Can choose to specify node type, otherwise defaults to Node.
-///
-/// # Panics
-/// 
-/// Panics if node_path cannot be found.
+/// Creates a GString from the provided string. Also takes the same format arguments as format!.
 /// 
 /// # Example
 /// 
-/// ```
-/// let mut label: Gd<Node> = nm!(self, "Player"); // Mutable reference to a child Node named "Player"
-/// let mut label: Gd<Label> = nm!(self, Label, "Player"); // Mutable reference to a child Label named "Player"
-/// let mut label: Gd<Label> = nm!(self, "Player", Label); // Same but with type and name switched
+/// ```ignore
+/// let greeting: GString = gstr!("hello"); // From a string
+/// let score: GString = gstr!(format!("Score: {}", self.score)); // From an already formatted string
+/// let score: GString = gstr!("Score: {}", self.score); // Same but formatting directly
 /// 
 /// // Expanded
-/// let mut label: Gd<Node> = self.base_mut().get_node_as::<Node>("Player");
-/// let mut label: Gd<Label> = self.base_mut().get_node_as::<Label>("Player");
+/// let greeting: GString = GString::from("hello");
+/// let score: GString = GString::from(&format!("Score: {}", self.score));
+/// let score: GString = GString::from(&format!("Score: {}", self.score));
 /// ```
 #[macro_export]
-macro_rules! nm {
-    ($self:ident, $node_path:expr) => {
-        $self.base_mut().get_node_as::<Node>($node_path)
-    };
-    ($self:ident, $node_type:ty, $node_path:expr) => {
-        $self.base_mut().get_node_as::<$node_type>($node_path)
+macro_rules! gstr {
+    ($string:expr) => {
+        GString::from($string)
     };
-    ($self:ident, $node_path:expr, $node_type:ty) => {
-        $self.base_mut().get_node_as::<$node_type>($node_path)
+    ($format:literal, $($arg:tt)*) => {
+        GString::from(&format!($format, $($arg)*))
     };
 }
 
-/// Simplifies connecting a signal on a node to to a callback on either self or a second provided node.
+/// Converts the provided value to a Variant.
 /// 
-/// Note: Callback function must be registered as a function with Godot, either in GDScript or by #\[func\].
+/// # Example
 /// 
-/// # Panics
+/// ```ignore
+/// // Store the player's name in a Variant
+/// let name: Variant = variant!("Maya");
 /// 
-/// Panics if any of the named strings do not correspond to anything in the engine.
+/// // Expanded
+/// let name: Variant = "Maya".to_variant();
+/// ```
+#[macro_export]
+macro_rules! variant {
+    ($value:expr) => {
+        $value.to_variant()
+    };
+}
+
+/// Creates a slice of Variants from the provided values of any types, for APIs that take arguments as &\[Variant\].
 /// 
 /// # Example
 /// 
-/// ```
-/// // Connect MobDetector.body_entered -> self.on_body_entered
-/// connect!(self, "MobDetector", "body_entered", "on_body_entered");
-/// // Expanded
-/// n!(self, "MobDetector").connect("body_entered".into(), self.base().callable("on_body_entered"));
+/// ```ignore
+/// // Call a GDScript method with a mix of argument types
+/// enemy.call("configure", varr![10, "orc", true]);
 /// 
-/// // Connect MobDetector.body_entered -> Ui.game_over
-/// connect!(self, "MobDetector", "body_entered", "Ui", "game_over");
 /// // Expanded
-/// n!(self, "MobDetector").connect("body_entered".into(), n!(self, "Ui").callable("game_over"));
+/// enemy.call("configure", &[10.to_variant(), "orc".to_variant(), true.to_variant()]);
 /// ```
 #[macro_export]
-macro_rules! connect {
-    ($self:ident, $node_path:expr, $signal:expr, $callback_name:expr) => {
-        n!($self, $node_path).connect($signal.into(), $self.base().callable($callback_name))
-    };
-    ($self:ident, $node_path_1:expr, $signal:expr, $node_path_2:expr, $callback_name:expr) => {
-        n!($self, $node_path_1).connect($signal.into(), n!($self, $node_path_2).callable($callback_name))
+macro_rules! varr {
+    ($($value:expr),* $(,)?) => {
+        &[$($value.to_variant()),*]
     };
 }
 
-/// Returns whether any input event has been pressed.
+/// Creates a Dictionary from the provided key-value pairs, converting each key and value to a Variant.
 /// 
 /// # Example
 /// 
-/// ```
-/// // See if player wants to continue game
-/// let continue: bool = any_press!();
+/// ```ignore
+/// let empty: VarDictionary = dict! {}; // An empty Dictionary
+/// let orc: VarDictionary = dict! { "hp" => 100, "name" => "orc" }; // A Dictionary with two entries
 /// 
 /// // Expanded
-/// let continue: bool = Input::singleton().is_anything_pressed();
+/// let empty: VarDictionary = VarDictionary::new();
+/// let orc: VarDictionary = {
+///     let mut dict = VarDictionary::new();
+///     dict.set("hp".to_variant(), 100.to_variant());
+///     dict.set("name".to_variant(), "orc".to_variant());
+///     dict
+/// };
 /// ```
 #[macro_export]
-macro_rules! any_press {
+macro_rules! dict {
     () => {
-        Input::singleton().is_anything_pressed()
+        VarDictionary::new()
     };
+    ($($key:expr => $value:expr),+ $(,)?) => {{
+        let mut dict = VarDictionary::new();
+        $(dict.set($key.to_variant(), $value.to_variant());)+
+        dict
+    }};
 }
 
-/// Returns whether the provided key has been pressed.
+/// Creates a Godot Array from the provided values. Untyped arrays convert each value to a Variant, while typed arrays,
+/// given as `type`, the element type and a semicolon, take the values as they are.
 /// 
 /// # Example
 /// 
-/// ```
-/// // See if player jumped
-/// let should_jump: bool = key_press!(Key::UP);
+/// ```ignore
+/// let loot: VarArray = garr![1, "sword", true]; // An untyped Array with three elements
+/// let path: VarArray = garr![Vector2::new(0.0, 0.0), Vector2::new(16.0, 0.0)]; // Any expression can come first
+/// let scores: Array<i32> = garr![type i32; 1, 2, 3]; // A typed Array with three elements
+/// let empty: Array<i32> = garr![type i32;]; // An empty typed Array
 /// 
 /// // Expanded
-/// let should_jump: bool = Input::singleton().is_key_pressed(Key::UP);
+/// let loot: VarArray = {
+///     let mut array = VarArray::new();
+///     array.push(&1.to_variant());
+///     array.push(&"sword".to_variant());
+///     array.push(&true.to_variant());
+///     array
+/// };
+/// let path: VarArray = {
+///     let mut array = VarArray::new();
+///     array.push(&Vector2::new(0.0, 0.0).to_variant());
+///     array.push(&Vector2::new(16.0, 0.0).to_variant());
+///     array
+/// };
+/// let scores: Array<i32> = {
+///     let mut array = Array::<i32>::new();
+///     array.push(1);
+///     array.push(2);
+///     array.push(3);
+///     array
+/// };
+/// let empty: Array<i32> = Array::<i32>::new();
 /// ```
 #[macro_export]
-macro_rules! key_press {
-    ($keycode:expr) => {
-        Input::singleton().is_key_pressed($keycode)
+macro_rules! garr {
+    () => {
+        VarArray::new()
+    };
+    (type $element_type:ty;) => {
+        Array::<$element_type>::new()
     };
+    (type $element_type:ty; $($value:expr),+ $(,)?) => {{
+        let mut array = Array::<$element_type>::new();
+        $(array.push($value);)+
+        array
+    }};
+    ($($value:expr),+ $(,)?) => {{
+        let mut array = VarArray::new();
+        $(array.push(&$value.to_variant());)+
+        array
+    }};
 }
 
-/// Returns whether the provided key has been pressed physically.
+/// Creates a Color from an HTML hex code, with or without the leading #, or from red, green, blue, and optionally alpha components between 0.0 and 1.0.
+/// 
+/// # Panics
+/// 
+/// Panics if the hex code is not a valid HTML color.
 /// 
 /// # Example
 /// 
-/// ```
-/// // See if player jumped
-/// let should_jump: bool = key_press_phys!(Key::UP);
+/// ```ignore
+/// let red: Color = color!("#ff0000"); // From a hex code
+/// let red: Color = color!(1.0, 0.0, 0.0); // From RGB components
+/// let faded_red: Color = color!(1.0, 0.0, 0.0, 0.5); // From RGBA components
 /// 
 /// // Expanded
-/// let should_jump: bool = Input::singleton().is_physical_key_pressed(Key::UP);
+/// let red: Color = Color::from_html("#ff0000").unwrap_or_else(|| panic!("Invalid HTML color {}", "\"#ff0000\""));
+/// let red: Color = Color::from_rgb(1.0, 0.0, 0.0);
+/// let faded_red: Color = Color::from_rgba(1.0, 0.0, 0.0, 0.5);
 /// ```
 #[macro_export]
-macro_rules! key_press_phys {
-    ($keycode:expr) => {
-        Input::singleton().is_physical_key_pressed($keycode)
+macro_rules! color {
+    ($hex:expr) => {
+        Color::from_html($hex).unwrap_or_else(|| panic!("Invalid HTML color {}", stringify!($hex)))
+    };
+    ($r:expr, $g:expr, $b:expr) => {
+        Color::from_rgb($r, $g, $b)
+    };
+    ($r:expr, $g:expr, $b:expr, $a:expr) => {
+        Color::from_rgba($r, $g, $b, $a)
     };
 }
 
-/// Returns whether the provided key's label has been pressed.
+/// Creates a Vector2 from its x and y components, or with both components set to the same value.
 /// 
 /// # Example
 /// 
-/// ```
-/// // See if player jumped
-/// let should_jump: bool = key_press_label!(Key::UP);
+/// ```ignore
+/// let position: Vector2 = vec2!(1.0, 2.0); // From components
+/// let scale: Vector2 = vec2!(0.5); // Both components 0.5
 /// 
 /// // Expanded
-/// let should_jump: bool = Input::singleton().is_key_label_pressed(Key::UP);
+/// let position: Vector2 = Vector2::new(1.0, 2.0);
+/// let scale: Vector2 = Vector2::splat(0.5);
 /// ```
 #[macro_export]
-macro_rules! key_press_label {
-    ($keycode:expr) => {
-        Input::singleton().is_key_label_pressed($keycode)
+macro_rules! vec2 {
+    ($value:expr) => {
+        Vector2::splat($value)
+    };
+    ($x:expr, $y:expr) => {
+        Vector2::new($x, $y)
     };
 }
 
-/// Returns whether the provided mouse button has been pressed.
+/// Creates a Vector3 from its x, y, and z components, or with all components set to the same value.
 /// 
 /// # Example
 /// 
-/// ```
-/// // See if player is shooting
-/// let shooting: bool = mouse_press!(MouseButton::LEFT);
+/// ```ignore
+/// let position: Vector3 = vec3!(1.0, 2.0, 3.0); // From components
+/// let scale: Vector3 = vec3!(0.5); // All components 0.5
 /// 
 /// // Expanded
-/// let shooting: bool = Input::singleton().is_mouse_button_pressed(MouseButton::LEFT);
+/// let position: Vector3 = Vector3::new(1.0, 2.0, 3.0);
+/// let scale: Vector3 = Vector3::splat(0.5);
 /// ```
 #[macro_export]
-macro_rules! mouse_press {
-    ($button:expr) => {
-        Input::singleton().is_mouse_button_pressed($button)
+macro_rules! vec3 {
+    ($value:expr) => {
+        Vector3::splat($value)
+    };
+    ($x:expr, $y:expr, $z:expr) => {
+        Vector3::new($x, $y, $z)
     };
 }
 
-/// Returns whether the provided joypad button on the provided gontroller has been pressed.
+/// Linearly interpolates between two floats by the provided weight, where 0.0 returns the first and 1.0 returns the second.
+/// 
+/// Note: Vectors and colors have their own lerp methods, such as Vector2::lerp.
 /// 
 /// # Example
 /// 
+/// ```ignore
+/// // Smoothly zoom the camera towards the target zoom
+/// self.zoom = lerp!(self.zoom, TARGET_ZOOM, 0.1);
+/// 
+/// // Expanded
+/// self.zoom = godot::global::lerpf(self.zoom, TARGET_ZOOM, 0.1);
 /// ```
-/// // See if player 1 jumped
-/// let should_jump: bool = joy_press!(0, JoyButton::A);
+#[macro_export]
+macro_rules! lerp {
+    ($from:expr, $to:expr, $weight:expr) => {
+        godot::global::lerpf($from, $to, $weight)
+    };
+}
+
+/// Linearly interpolates between two angles in radians by the provided weight, taking the shortest path around the circle.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Smoothly turn the turret towards its target
+/// self.angle = lerp_angle!(self.angle, target_angle, 0.1);
 /// 
 /// // Expanded
-/// let should_jump: bool = Input::singleton().is_joy_button_pressed(0, JoyButton::A);
+/// self.angle = godot::global::lerp_angle(self.angle, target_angle, 0.1);
 /// ```
 #[macro_export]
-macro_rules! joy_press {
-    ($device:expr, $button:expr) => {
-        Input::singleton().is_joy_button_pressed($device, $button)
+macro_rules! lerp_angle {
+    ($from:expr, $to:expr, $weight:expr) => {
+        godot::global::lerp_angle($from, $to, $weight)
     };
 }
 
-/// Returns whether the provided action has been pressed.
+/// Clamps the global position of self so it stays inside the visible rectangle of its viewport.
+/// 
+/// Note: Self must be a Node2D. Assumes the viewport is not moved by a camera, as is usual for single-screen arcade games.
 /// 
 /// # Panics
 /// 
-/// Panics if provided action is not found in the Godot.
+/// Panics if the current node has no viewport.
 /// 
 /// # Example
 /// 
-/// ```
-/// // See if player jumped
-/// let should_jump: bool = act_press!("jump");
+/// ```ignore
+/// // Stop the player from leaving the screen
+/// clamp_to_screen!(self);
 /// 
 /// // Expanded
-/// let should_jump: bool = Input::singleton().is_action_pressed("jump".into());
+/// {
+///     let screen = viewport_rect!(self);
+///     let position = self.base().get_global_position();
+///     self.base_mut().set_global_position(position.clamp(screen.position, screen.end()));
+/// };
+/// ```
+/// 
+/// ```
+/// # use godot_macros::*;
+/// # #[derive(Clone, Copy, Debug, PartialEq)] struct Vector2 { x: f32, y: f32 }
+/// # impl Vector2 {
+/// #     fn new(x: f32, y: f32) -> Self { Self { x, y } }
+/// #     fn clamp(self, min: Self, max: Self) -> Self { Self::new(self.x.clamp(min.x, max.x), self.y.clamp(min.y, max.y)) }
+/// # }
+/// # impl std::ops::Add for Vector2 { type Output = Self; fn add(self, other: Self) -> Self { Self::new(self.x + other.x, self.y + other.y) } }
+/// # impl std::ops::Sub for Vector2 { type Output = Self; fn sub(self, other: Self) -> Self { Self::new(self.x - other.x, self.y - other.y) } }
+/// # struct Rect2 { position: Vector2, size: Vector2 }
+/// # impl Rect2 { fn end(&self) -> Vector2 { self.position + self.size } }
+/// # struct Viewport;
+/// # impl Viewport {
+/// #     fn get_visible_rect(&self) -> Rect2 { Rect2 { position: Vector2::new(100.0, 50.0), size: Vector2::new(640.0, 480.0) } }
+/// # }
+/// # struct Base { position: Vector2 }
+/// # impl Base {
+/// #     fn get_viewport(&self) -> Option<Viewport> { Some(Viewport) }
+/// #     fn get_name(&self) -> &str { "Ship" }
+/// #     fn get_global_position(&self) -> Vector2 { self.position }
+/// #     fn set_global_position(&mut self, position: Vector2) { self.position = position }
+/// # }
+/// # struct Ship { base: Base }
+/// # impl Ship {
+/// #     fn base(&self) -> &Base { &self.base }
+/// #     fn base_mut(&mut self) -> &mut Base { &mut self.base }
+/// # }
+/// // The visible rect of the viewport is 640x480, starting at (100, 50)
+/// let mut ship = Ship { base: Base { position: Vector2::new(800.0, 0.0) } };
+/// clamp_to_screen!(ship);
+/// assert_eq!(ship.base().get_global_position(), Vector2::new(740.0, 50.0)); // Pushed back onto the nearest edges
+/// 
+/// ship.base_mut().set_global_position(Vector2::new(320.0, 240.0));
+/// clamp_to_screen!(ship);
+/// assert_eq!(ship.base().get_global_position(), Vector2::new(320.0, 240.0)); // Already on screen, so unchanged
 /// ```
 #[macro_export]
-macro_rules! act_press {
-    ($action:expr) => {
-        Input::singleton().is_action_pressed($action.into())
-    };
+macro_rules! clamp_to_screen {
+    ($self:ident) => {{
+        let screen = viewport_rect!($self);
+        let position = $self.base().get_global_position();
+        $self.base_mut().set_global_position(position.clamp(screen.position, screen.end()));
+    }};
 }
 
-/// Returns whether the provided action has been pressed down.
+/// Wraps the global position of self around the edges of the visible rectangle of its viewport, so leaving one edge
+/// enters from the opposite edge.
+/// 
+/// Note: Self must be a Node2D. Assumes the viewport is not moved by a camera, as is usual for single-screen arcade games.
 /// 
 /// # Panics
 /// 
-/// Panics if provided action is not found in the Godot.
+/// Panics if the current node has no viewport.
 /// 
 /// # Example
 /// 
-/// ```
-/// // See if player started jumping
-/// let begun_jumping: bool = act_press_down!("jump");
+/// ```ignore
+/// // Asteroids-style screen wrapping
+/// wrap_to_screen!(self);
 /// 
 /// // Expanded
-/// let begun_jumping: bool = Input::singleton().is_action_just_pressed("jump".into());
+/// {
+///     let screen = viewport_rect!(self);
+///     let offset = self.base().get_global_position() - screen.position;
+///     let wrapped = Vector2::new(offset.x.rem_euclid(screen.size.x), offset.y.rem_euclid(screen.size.y));
+///     self.base_mut().set_global_position(screen.position + wrapped);
+/// };
+/// ```
+/// 
+/// ```
+/// # use godot_macros::*;
+/// # #[derive(Clone, Copy, Debug, PartialEq)] struct Vector2 { x: f32, y: f32 }
+/// # impl Vector2 {
+/// #     fn new(x: f32, y: f32) -> Self { Self { x, y } }
+/// #     fn clamp(self, min: Self, max: Self) -> Self { Self::new(self.x.clamp(min.x, max.x), self.y.clamp(min.y, max.y)) }
+/// # }
+/// # impl std::ops::Add for Vector2 { type Output = Self; fn add(self, other: Self) -> Self { Self::new(self.x + other.x, self.y + other.y) } }
+/// # impl std::ops::Sub for Vector2 { type Output = Self; fn sub(self, other: Self) -> Self { Self::new(self.x - other.x, self.y - other.y) } }
+/// # struct Rect2 { position: Vector2, size: Vector2 }
+/// # impl Rect2 { fn end(&self) -> Vector2 { self.position + self.size } }
+/// # struct Viewport;
+/// # impl Viewport {
+/// #     fn get_visible_rect(&self) -> Rect2 { Rect2 { position: Vector2::new(100.0, 50.0), size: Vector2::new(640.0, 480.0) } }
+/// # }
+/// # struct Base { position: Vector2 }
+/// # impl Base {
+/// #     fn get_viewport(&self) -> Option<Viewport> { Some(Viewport) }
+/// #     fn get_name(&self) -> &str { "Ship" }
+/// #     fn get_global_position(&self) -> Vector2 { self.position }
+/// #     fn set_global_position(&mut self, position: Vector2) { self.position = position }
+/// # }
+/// # struct Ship { base: Base }
+/// # impl Ship {
+/// #     fn base(&self) -> &Base { &self.base }
+/// #     fn base_mut(&mut self) -> &mut Base { &mut self.base }
+/// # }
+/// // The visible rect of the viewport is 640x480, starting at (100, 50)
+/// let mut ship = Ship { base: Base { position: Vector2::new(800.0, 0.0) } };
+/// wrap_to_screen!(ship);
+/// assert_eq!(ship.base().get_global_position(), Vector2::new(160.0, 480.0)); // Past the right and top edges, so enters left and bottom
+/// 
+/// ship.base_mut().set_global_position(Vector2::new(320.0, 240.0));
+/// wrap_to_screen!(ship);
+/// assert_eq!(ship.base().get_global_position(), Vector2::new(320.0, 240.0)); // Already on screen, so unchanged
 /// ```
 #[macro_export]
-macro_rules! act_press_down {
-    ($action:expr) => {
-        Input::singleton().is_action_just_pressed($action.into())
-    };
+macro_rules! wrap_to_screen {
+    ($self:ident) => {{
+        let screen = viewport_rect!($self);
+        let offset = $self.base().get_global_position() - screen.position;
+        let wrapped = Vector2::new(offset.x.rem_euclid(screen.size.x), offset.y.rem_euclid(screen.size.y));
+        $self.base_mut().set_global_position(screen.position + wrapped);
+    }};
 }
 
-/// Returns whether the provided action has been unpressed.
-/// 
-/// # Panics
+/// Draws a line, circle, or rectangle on self, but only in debug builds. In release builds the call is skipped and
+/// optimized away, so debug visualizations can stay in place.
 /// 
-/// Panics if provided action is not found in the Godot.
+/// Note: Self must be a CanvasItem, and like all drawing this must be called from the node's draw function.
 /// 
 /// # Example
 /// 
+/// ```ignore
+/// // Show where the enemy is heading
+/// debug_draw!(self, line, Vector2::ZERO, self.velocity, Color::RED);
+/// // Show the enemy's aggro range
+/// debug_draw!(self, circle, Vector2::ZERO, AGGRO_RANGE, Color::from_rgba(1.0, 0.0, 0.0, 0.2));
+/// // Show the enemy's hitbox
+/// debug_draw!(self, rect, self.hitbox, Color::GREEN);
+/// 
+/// // Expanded, the arguments are evaluated first so they can borrow self
+/// if cfg!(debug_assertions) {
+///     let (from, to, color) = (Vector2::ZERO, self.velocity, Color::RED);
+///     self.base_mut().draw_line(from, to, color);
+/// }
+/// if cfg!(debug_assertions) {
+///     let (position, radius, color) = (Vector2::ZERO, AGGRO_RANGE, Color::from_rgba(1.0, 0.0, 0.0, 0.2));
+///     self.base_mut().draw_circle(position, radius, color);
+/// }
+/// if cfg!(debug_assertions) {
+///     let (rect, color) = (self.hitbox, Color::GREEN);
+///     self.base_mut().draw_rect(rect, color);
+/// }
 /// ```
-/// // See if player stopped jumping
-/// let jump_stopped: bool = act_press_up!("jump");
 /// 
-/// // Expanded
-/// let jump_stopped: bool = Input::singleton().is_action_just_released("jump".into());
+/// ```
+/// # use godot_macros::*;
+/// # struct Base { draws: Vec<&'static str> }
+/// # impl Base {
+/// #     fn draw_line(&mut self, _from: (f32, f32), _to: (f32, f32), _color: &str) { self.draws.push("line") }
+/// #     fn draw_circle(&mut self, _position: (f32, f32), _radius: f32, _color: &str) { self.draws.push("circle") }
+/// #     fn draw_rect(&mut self, _rect: (f32, f32, f32, f32), _color: &str) { self.draws.push("rect") }
+/// # }
+/// # struct Enemy { base: Base }
+/// # impl Enemy { fn base_mut(&mut self) -> &mut Base { &mut self.base } }
+/// let mut enemy = Enemy { base: Base { draws: Vec::new() } };
+/// debug_draw!(enemy, line, (0.0, 0.0), (16.0, 0.0), "red");
+/// debug_draw!(enemy, circle, (0.0, 0.0), 64.0, "red");
+/// debug_draw!(enemy, rect, (-8.0, -8.0, 16.0, 16.0), "green");
+/// 
+/// // Nothing is drawn in release builds, which `RUSTDOCFLAGS="-C debug-assertions=off" cargo test --doc` checks
+/// if cfg!(debug_assertions) {
+///     assert_eq!(enemy.base.draws, ["line", "circle", "rect"]);
+/// } else {
+///     assert!(enemy.base.draws.is_empty());
+/// }
 /// ```
 #[macro_export]
-macro_rules! act_press_up {
-    ($action:expr) => {
-        Input::singleton().is_action_just_released($action.into())
+macro_rules! debug_draw {
+    ($self:ident, line, $from:expr, $to:expr, $color:expr) => {
+        if cfg!(debug_assertions) {
+            let (from, to, color) = ($from, $to, $color);
+            $self.base_mut().draw_line(from, to, color);
+        }
+    };
+    ($self:ident, circle, $position:expr, $radius:expr, $color:expr) => {
+        if cfg!(debug_assertions) {
+            let (position, radius, color) = ($position, $radius, $color);
+            $self.base_mut().draw_circle(position, radius, color);
+        }
+    };
+    ($self:ident, rect, $rect:expr, $color:expr) => {
+        if cfg!(debug_assertions) {
+            let (rect, color) = ($rect, $color);
+            $self.base_mut().draw_rect(rect, color);
+        }
     };
 }
 
-/// Returns the strength by which the provided action is pressed down.
+/// Runs the provided statements only when running inside the editor, for @tool-style nodes that behave differently in
+/// the editor and in game.
 /// 
-/// # Panics
+/// # Example
 /// 
-/// Panics if provided action is not found in the Godot.
+/// ```ignore
+/// // Only redraw the preview while editing the level
+/// editor_only! {
+///     self.update_preview();
+/// }
+/// 
+/// // Expanded
+/// if Engine::singleton().is_editor_hint() {
+///     self.update_preview();
+/// }
+/// ```
+#[macro_export]
+macro_rules! editor_only {
+    ($($body:tt)*) => {
+        if Engine::singleton().is_editor_hint() {
+            $($body)*
+        }
+    };
+}
+
+/// Runs the provided statements only when not running inside the editor, for @tool-style nodes that behave differently
+/// in the editor and in game.
 /// 
 /// # Example
 /// 
-/// ```
-/// // Get speed so you can speed up the more you push the stick
-/// let speed_multiplier: f32 = act_str!("move_right");
+/// ```ignore
+/// // Only start the enemy's AI in game
+/// runtime_only! {
+///     self.start_ai();
+/// }
 /// 
 /// // Expanded
-/// let speed_multiplier: f32 = Input::singleton().get_action_strength("move_right".into());
+/// if !Engine::singleton().is_editor_hint() {
+///     self.start_ai();
+/// }
 /// ```
 #[macro_export]
-macro_rules! act_str {
-    ($action:expr) => {
-        Input::singleton().get_action_strength($action.into())
+macro_rules! runtime_only {
+    ($($body:tt)*) => {
+        if !Engine::singleton().is_editor_hint() {
+            $($body)*
+        }
     };
 }
 
-/// Returns the raw strength by which the provided action is pressed down.
+/// Returns the root Window of the tree of the current node.
 /// 
 /// # Panics
 /// 
-/// Panics if provided action is not found in the Godot.
+/// Panics if the current node has no tree, or the tree has no root.
 /// 
 /// # Example
 /// 
-/// ```
-/// // Get speed so you can speed up the more you push the stick
-/// let speed_multiplier: f32 = act_str_raw!("move_right");
+/// ```ignore
+/// // Make the game window borderless
+/// root!(self).set_flag(Flags::BORDERLESS, true);
 /// 
 /// // Expanded
-/// let speed_multiplier: f32 = Input::singleton().get_action_raw_strength("move_right".into());
+/// self.base().get_tree().unwrap_or_else(|| panic!("Node '{}' has no tree", self.base().get_name()))
+///     .get_root()
+///     .unwrap_or_else(|| panic!("Tree of node '{}' has no root", self.base().get_name()))
+///     .set_flag(Flags::BORDERLESS, true);
 /// ```
 #[macro_export]
-macro_rules! act_str_raw {
-    ($action:expr) => {
-        Input::singleton().get_action_raw_strength($action.into())
+macro_rules! root {
+    ($self:ident) => {
+        $self.base().get_tree().unwrap_or_else(|| panic!("Node '{}' has no tree", $self.base().get_name()))
+            .get_root()
+            .unwrap_or_else(|| panic!("Tree of node '{}' has no root", $self.base().get_name()))
     };
 }
 
-/// Returns the position on an a given access between two actions.
+/// Returns the root node of the currently running scene. Can choose to specify node type, otherwise defaults to Node.
 /// 
 /// # Panics
 /// 
-/// Panics if either of the provided actions is not found in Godot.
+/// Panics if the current node has no tree, there is no current scene, or the scene's root node is not of the given type.
 /// 
 /// # Example
 /// 
+/// ```ignore
+/// let scene: Gd<Node> = current_scene!(self); // Root node of the running scene
+/// let level: Gd<Level> = current_scene!(self, Level); // Same but cast to Level
+/// 
+/// // Expanded
+/// let scene: Gd<Node> = self.base().get_tree().unwrap_or_else(|| panic!("Node '{}' has no tree", self.base().get_name()))
+///     .get_current_scene()
+///     .unwrap_or_else(|| panic!("Tree of node '{}' has no current scene", self.base().get_name()));
+/// let level: Gd<Level> = current_scene!(self).cast::<Level>();
 /// ```
-/// // Get controller's Y axis
-/// let y_axis: f32 = act_axis!("move_left", "move_right");
+#[macro_export]
+macro_rules! current_scene {
+    ($self:ident) => {
+        $self.base().get_tree().unwrap_or_else(|| panic!("Node '{}' has no tree", $self.base().get_name()))
+            .get_current_scene()
+            .unwrap_or_else(|| panic!("Tree of node '{}' has no current scene", $self.base().get_name()))
+    };
+    ($self:ident, $node_type:ty) => {
+        current_scene!($self).cast::<$node_type>()
+    };
+}
+
+/// Sets self as the owner of the provided node. When a scene is saved, only nodes owned by its root are saved with it,
+/// so nodes added at runtime by procedural builders or editor tools need an owner to persist.
+/// 
+/// Note: The owner must be an ancestor of the node, so add the node to the tree first.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Add a generated wall to the level so it is saved with it
+/// add_child!(self, wall.clone());
+/// set_owner!(wall, self);
 /// 
 /// // Expanded
-/// let y_axis: f32 = Input::singleton().get_axis("move_left".into(), "move_right".into());
+/// wall.set_owner(&self_gd!(self));
 /// ```
 #[macro_export]
-macro_rules! act_axis {
-    ($negative_action:expr, $positive_action:expr) => {
-        Input::singleton().get_axis($negative_action.into(), $positive_action.into())
+macro_rules! set_owner {
+    ($node:expr, $self:ident) => {
+        $node.set_owner(&self_gd!($self))
     };
 }
 
-/// Macro for quickly emitting signal with no arguments.
+/// Duplicates the provided node, including its children. Can choose to specify node type, otherwise defaults to Node.
+/// Can also choose the DuplicateFlags that control what is copied, otherwise copies signals, groups, and scripts.
 /// 
 /// # Panics
 /// 
-/// Panics if the provided signal does not exist on self.
+/// Panics if the node cannot be duplicated, or the copy is not of the given type.
 /// 
 /// # Example
+/// 
+/// ```ignore
+/// let copy: Gd<Node> = dup!(template); // Copy of the template node
+/// let mob: Gd<Mob> = dup!(Mob, template); // Same but cast to Mob
+/// let mob: Gd<Mob> = dup!(Mob, template, flags: DuplicateFlags::USE_INSTANTIATION); // Only copy the template's instantiation
+/// 
+/// // Expanded
+/// let copy: Gd<Node> = {
+///     let node = &template;
+///     node.duplicate().unwrap_or_else(|| panic!("Failed to duplicate node '{}'", node.get_name()))
+/// };
+/// let mob: Gd<Mob> = dup!(template).cast::<Mob>();
+/// let mob: Gd<Mob> = {
+///     let node = &template;
+///     node.duplicate_ex().flags(DuplicateFlags::USE_INSTANTIATION).done().unwrap_or_else(|| panic!("Failed to duplicate node '{}'", node.get_name()))
+/// }.cast::<Mob>();
 /// ```
-/// // Emit that current node has been hit
-/// emit!(self, "hit");
+#[macro_export]
+macro_rules! dup {
+    ($node:expr) => {{
+        let node = &$node;
+        node.duplicate().unwrap_or_else(|| panic!("Failed to duplicate node '{}'", node.get_name()))
+    }};
+    ($node:expr, flags: $flags:expr) => {{
+        let node = &$node;
+        node.duplicate_ex().flags($flags).done().unwrap_or_else(|| panic!("Failed to duplicate node '{}'", node.get_name()))
+    }};
+    ($node_type:ty, $node:expr) => {
+        dup!($node).cast::<$node_type>()
+    };
+    ($node_type:ty, $node:expr, flags: $flags:expr) => {
+        dup!($node, flags: $flags).cast::<$node_type>()
+    };
+}
+
+/// Returns the index of self among its siblings, starting at 0.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Take turns in the order the units are in the tree
+/// let turn_order: i32 = index!(self);
 /// 
 /// // Expanded
-/// self.base_mut().emit_signal("hit".into(), &[]);
+/// let turn_order: i32 = self.base().get_index();
 /// ```
 #[macro_export]
-macro_rules! emit {
-    ($self:ident, $signal:expr) => {
-        $self.base_mut().emit_signal($signal.into(), &[])
+macro_rules! index {
+    ($self:ident) => {
+        $self.base().get_index()
     };
 }
 
-/// Frees provided self.
+/// Moves the provided child of self to a new index among its siblings. Negative indices count from the last child.
 /// 
 /// # Example
 /// 
+/// ```ignore
+/// // Bring the clicked card to the back of the draw order, so it's drawn on top
+/// move_child!(self, card, -1);
+/// 
+/// // Expanded, the arguments are evaluated first so they can borrow self
+/// {
+///     let child = card;
+///     let index = -1;
+///     self.base_mut().move_child(&child, index)
+/// };
 /// ```
-/// // Destroy self
-/// free!(self);
+#[macro_export]
+macro_rules! move_child {
+    ($self:ident, $child:expr, $index:expr) => {{
+        let child = $child;
+        let index = $index;
+        $self.base_mut().move_child(&child, index)
+    }};
+}
+
+/// Returns the RID of the provided object, for use with low-level servers like PhysicsServer2D and RenderingServer.
+/// 
+/// Objects that expose get_rid() include CollisionObject2D, CollisionObject3D and their subclasses (bodies and areas),
+/// Viewport, and any Resource. CanvasItem-based nodes (Node2D and Control) expose their RenderingServer canvas item
+/// instead; use the `canvas:` arm for those.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Teleport self, a physics body, straight through the physics server
+/// let body_rid = rid!(self.base());
+/// PhysicsServer2D::singleton().body_set_state(body_rid, BodyState::TRANSFORM, &transform.to_variant());
 /// 
 /// // Expanded
-/// self.base_mut().queue_free();
+/// let body_rid = self.base().get_rid();
+/// PhysicsServer2D::singleton().body_set_state(body_rid, BodyState::TRANSFORM, &transform.to_variant());
+/// 
+/// // Draw directly onto a Sprite2D's canvas item
+/// let canvas_rid = rid!(canvas: n!(self, Sprite2D, "Sprite2D"));
+/// RenderingServer::singleton().canvas_item_add_circle(canvas_rid, Vector2::ZERO, 8.0, Color::RED);
+/// 
+/// // Expanded
+/// let canvas_rid = n!(self, Sprite2D, "Sprite2D").get_canvas_item();
+/// RenderingServer::singleton().canvas_item_add_circle(canvas_rid, Vector2::ZERO, 8.0, Color::RED);
 /// ```
 #[macro_export]
-macro_rules! free {
-    ($self:ident) => {
-        $self.base_mut().queue_free()
+macro_rules! rid {
+    (canvas: $node:expr) => {
+        $node.get_canvas_item()
+    };
+    ($node:expr) => {
+        $node.get_rid()
     };
 }
 
-/// Reloads the scene which this node is a part of.
+/// Connects several signals at once by expanding to one connect! per entry. Entries are separated by commas, and are
+/// either `"Node" : "signal" => "callback"` to connect to a callback on self, or `"Node" : "signal" => "Target" : "callback"`
+/// to connect to a callback on a second node.
 /// 
 /// # Panics
 /// 
-/// Panics if the tree of the current node cannot be reloaded.
+/// Panics if any of the named strings do not correspond to anything in the engine.
 /// 
 /// # Example
 /// 
+/// ```ignore
+/// // Wire up the main menu
+/// connect_all!(self, {
+///     "StartButton" : "pressed" => "on_start_pressed",
+///     "QuitButton" : "pressed" => "Ui" : "on_quit_pressed",
+/// });
+/// 
+/// // Expanded
+/// connect!(self, "StartButton", "pressed", "on_start_pressed");
+/// connect!(self, "QuitButton", "pressed", "Ui", "on_quit_pressed");
 /// ```
-/// // Reload current scene
-/// reload!(self);
+#[macro_export]
+macro_rules! connect_all {
+    ($self:ident, { }) => {};
+    ($self:ident, { $node_path_1:literal : $signal:literal => $node_path_2:literal : $callback_name:literal $(, $($rest:tt)*)? }) => {
+        connect!($self, $node_path_1, $signal, $node_path_2, $callback_name);
+        connect_all!($self, { $($($rest)*)? });
+    };
+    ($self:ident, { $node_path:literal : $signal:literal => $callback_name:literal $(, $($rest:tt)*)? }) => {
+        connect!($self, $node_path, $signal, $callback_name);
+        connect_all!($self, { $($($rest)*)? });
+    };
+}
+
+/// Runs the enclosed block at idle time by wrapping it in a Callable and calling it deferred, making it safe to mutate the tree from physics callbacks and signals.
+/// The block is moved into a `'static` closure, so it cannot borrow self; clone any Gd handles it needs beforehand and capture those instead.
+/// 
+/// Note: Must be used on the main thread, as the Callable is single-threaded.
+/// 
+/// # Example
+/// 
+/// ```ignore
+/// // Spawn the drop once the body_entered signal has finished flushing
+/// let mut level = self.base().get_parent().unwrap();
+/// let pickup = instantiate!("res://pickup.tscn");
+/// defer! {
+///     level.add_child(&pickup);
+/// }
 /// 
 /// // Expanded
-/// self.base_mut().get_tree().expect("Node has no tree").reload_current_scene();
+/// let mut level = self.base().get_parent().unwrap();
+/// let pickup = instantiate!("res://pickup.tscn");
+/// Callable::from_fn("defer", move |_| {
+///     level.add_child(&pickup);
+/// }).call_deferred(&[]);
 /// ```
 #[macro_export]
-macro_rules! reload {
-    ($self:ident) => {
-        $self.base_mut().get_tree().expect("Node has no tree").reload_current_scene()
+macro_rules! defer {
+    ($($body:tt)*) => {
+        Callable::from_fn("defer", move |_| {
+            $($body)*
+        }).call_deferred(&[])
     };
 }
\ No newline at end of file